@@ -0,0 +1,299 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use crate::models::{Environment, SparkEventLog, YarnInfo};
+
+/// Spill totals aggregated across every task in the application.
+#[derive(Debug, Clone, Default)]
+pub struct SpillSummary {
+    pub memory_bytes_spilled: u64,
+    pub disk_bytes_spilled: u64,
+    pub tasks_with_spill: usize,
+}
+
+pub fn compute_spill_summary(event_log: &SparkEventLog) -> SpillSummary {
+    let mut summary = SpillSummary::default();
+
+    for metrics in event_log.tasks.values().filter_map(|t| t.metrics.as_ref()) {
+        summary.memory_bytes_spilled += metrics.memory_bytes_spilled;
+        summary.disk_bytes_spilled += metrics.disk_bytes_spilled;
+        if metrics.memory_bytes_spilled > 0 || metrics.disk_bytes_spilled > 0 {
+            summary.tasks_with_spill += 1;
+        }
+    }
+
+    summary
+}
+
+/// Configured shuffle partitions next to what the tasks actually used.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShufflePartitionInfo {
+    /// `spark.sql.shuffle.partitions`, or Spark's default of 200.
+    pub configured: u64,
+    /// Most distinct partitions read by any shuffle-reading stage attempt.
+    pub observed: Option<usize>,
+    pub aqe_enabled: bool,
+    /// Partition count AQE settled on, when its metric updates report one.
+    pub aqe_coalesced: Option<i64>,
+}
+
+impl ShufflePartitionInfo {
+    /// More than 4x the partitions any stage used suggests the setting is too high.
+    pub fn is_oversized(&self) -> bool {
+        self.observed.is_some_and(|observed| self.configured > observed as u64 * 4)
+    }
+}
+
+pub fn compute_shuffle_partition_info(event_log: &SparkEventLog) -> ShufflePartitionInfo {
+    let properties = &event_log.environment.spark_properties;
+
+    let mut partitions: HashMap<(u64, u64), HashSet<u64>> = HashMap::new();
+    for task in event_log.tasks.values() {
+        let reads_shuffle = task.metrics.as_ref().is_some_and(|m| m.shuffle_read_metrics.is_some());
+        if reads_shuffle {
+            partitions.entry((task.stage_id, task.stage_attempt_id)).or_default().insert(task.partition_id);
+        }
+    }
+
+    let aqe_coalesced = event_log.sql_executions.values()
+        .flat_map(|e| e.aqe_metric_updates.iter())
+        .filter(|(name, _)| name == "number of partitions")
+        .map(|(_, value)| *value)
+        .max();
+
+    ShufflePartitionInfo {
+        configured: properties.get("spark.sql.shuffle.partitions")
+            .and_then(|n| n.trim().parse().ok())
+            .unwrap_or(200),
+        observed: partitions.values().map(|p| p.len()).max(),
+        aqe_enabled: properties.get("spark.sql.adaptive.enabled").is_some_and(|v| v.trim() == "true"),
+        aqe_coalesced,
+    }
+}
+
+/// An executor that ran noticeably more or fewer tasks than the average.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutorImbalance {
+    pub executor_id: String,
+    pub task_count: usize,
+    /// Signed: negative for executors that ran fewer tasks than the mean.
+    pub deviation_from_mean_pct: f64,
+}
+
+/// How far an executor's task count may stray from the mean before it is flagged.
+const IMBALANCE_THRESHOLD_PCT: f64 = 30.0;
+
+/// Executors whose task count deviates more than 30% from the mean, worst
+/// first. Uneven task counts point to data skew or scheduling problems
+/// (e.g. locality waits piling work onto a few hosts).
+pub fn executor_imbalance(event_log: &SparkEventLog) -> Vec<ExecutorImbalance> {
+    // Executors that ran nothing count too; the driver only runs tasks in local mode
+    let mut task_counts: HashMap<&str, usize> = event_log.executors.keys()
+        .map(|id| (id.as_str(), 0))
+        .collect();
+    for task in event_log.tasks.values().filter(|t| t.executor_id != "driver") {
+        *task_counts.entry(task.executor_id.as_str()).or_default() += 1;
+    }
+    if task_counts.len() < 2 {
+        return Vec::new();
+    }
+
+    let mean = task_counts.values().sum::<usize>() as f64 / task_counts.len() as f64;
+    if mean == 0.0 {
+        return Vec::new();
+    }
+
+    let mut imbalanced: Vec<_> = task_counts.into_iter()
+        .map(|(executor_id, task_count)| ExecutorImbalance {
+            executor_id: executor_id.to_string(),
+            task_count,
+            deviation_from_mean_pct: (task_count as f64 - mean) / mean * 100.0,
+        })
+        .filter(|e| e.deviation_from_mean_pct.abs() > IMBALANCE_THRESHOLD_PCT)
+        .collect();
+    imbalanced.sort_by(|a, b| {
+        b.deviation_from_mean_pct.abs()
+            .total_cmp(&a.deviation_from_mean_pct.abs())
+            .then_with(|| a.executor_id.cmp(&b.executor_id))
+    });
+    imbalanced
+}
+
+/// Where the driver ran, from `spark.submit.deployMode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeploymentMode {
+    Client,
+    Cluster,
+    Unknown,
+}
+
+impl fmt::Display for DeploymentMode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            DeploymentMode::Client => "client",
+            DeploymentMode::Cluster => "cluster",
+            DeploymentMode::Unknown => "unknown",
+        };
+        f.write_str(label)
+    }
+}
+
+pub fn detect_deployment_mode(env: &Environment) -> DeploymentMode {
+    match env.spark_properties.get("spark.submit.deployMode").map(|mode| mode.trim()) {
+        Some("client") => DeploymentMode::Client,
+        Some("cluster") => DeploymentMode::Cluster,
+        _ => DeploymentMode::Unknown,
+    }
+}
+
+/// The resource manager the application ran on, from `spark.master`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClusterManager {
+    Yarn,
+    Kubernetes,
+    Mesos,
+    Standalone,
+    Local,
+    Unknown,
+}
+
+impl fmt::Display for ClusterManager {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let label = match self {
+            ClusterManager::Yarn => "YARN",
+            ClusterManager::Kubernetes => "Kubernetes",
+            ClusterManager::Mesos => "Mesos",
+            ClusterManager::Standalone => "Standalone",
+            ClusterManager::Local => "Local",
+            ClusterManager::Unknown => "Unknown",
+        };
+        f.write_str(label)
+    }
+}
+
+pub fn detect_cluster_manager(env: &Environment) -> ClusterManager {
+    let Some(master) = env.spark_properties.get("spark.master") else {
+        // Kubernetes submissions always carry spark.kubernetes.* settings
+        if env.spark_properties.keys().any(|key| key.starts_with("spark.kubernetes.")) {
+            return ClusterManager::Kubernetes;
+        }
+        return ClusterManager::Unknown;
+    };
+
+    if master == "yarn" || master.starts_with("yarn-") {
+        ClusterManager::Yarn
+    } else if master.starts_with("k8s://") {
+        ClusterManager::Kubernetes
+    } else if master.starts_with("mesos://") {
+        ClusterManager::Mesos
+    } else if master.starts_with("spark://") {
+        ClusterManager::Standalone
+    } else if master.starts_with("local") {
+        ClusterManager::Local
+    } else {
+        ClusterManager::Unknown
+    }
+}
+
+/// Queue, tags and attempt limit for applications submitted to YARN.
+pub fn extract_yarn_info(env: &Environment) -> Option<YarnInfo> {
+    if detect_cluster_manager(env) != ClusterManager::Yarn {
+        return None;
+    }
+
+    let properties = &env.spark_properties;
+    let tags = properties.get("spark.yarn.tags")
+        .map(|tags| {
+            tags.split(',')
+                .map(|tag| tag.trim().to_string())
+                .filter(|tag| !tag.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(YarnInfo {
+        queue: properties.get("spark.yarn.queue").cloned(),
+        tags,
+        max_app_attempts: properties.get("spark.yarn.maxAppAttempts").and_then(|n| n.parse().ok()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SparkEventLogBuilder;
+
+    fn environment(properties: &[(&str, &str)]) -> Environment {
+        Environment {
+            spark_properties: properties.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
+            ..Environment::default()
+        }
+    }
+
+    #[test]
+    fn detects_deployment_mode_and_cluster_manager() {
+        let env = environment(&[("spark.submit.deployMode", "cluster"), ("spark.master", "k8s://https://api:6443")]);
+        assert_eq!(detect_deployment_mode(&env), DeploymentMode::Cluster);
+        assert_eq!(detect_cluster_manager(&env), ClusterManager::Kubernetes);
+
+        let env = environment(&[("spark.master", "local[*]")]);
+        assert_eq!(detect_deployment_mode(&env), DeploymentMode::Unknown);
+        assert_eq!(detect_cluster_manager(&env), ClusterManager::Local);
+
+        let env = environment(&[("spark.kubernetes.namespace", "etl")]);
+        assert_eq!(detect_cluster_manager(&env), ClusterManager::Kubernetes);
+    }
+
+    #[test]
+    fn flags_executors_far_from_mean_task_count() {
+        let mut builder = SparkEventLogBuilder::new()
+            .add_executor("1", "a")
+            .add_executor("2", "b")
+            .add_executor("3", "c")
+            .add_executor("4", "d")
+            .add_stage(0, 20);
+        // 8, 6, 5 and 1 tasks: a mean of 5
+        let assignments = [("1", 8), ("2", 6), ("3", 5), ("4", 1)];
+        let mut task_id = 0;
+        for (executor_id, count) in assignments {
+            for _ in 0..count {
+                builder = builder.add_task(task_id, 0, executor_id);
+                task_id += 1;
+            }
+        }
+
+        let imbalance = executor_imbalance(&builder.build());
+        let flagged: Vec<_> = imbalance.iter().map(|e| (e.executor_id.as_str(), e.task_count)).collect();
+        assert_eq!(flagged, vec![("4", 1), ("1", 8)]);
+        assert_eq!(imbalance[0].deviation_from_mean_pct, -80.0);
+        assert!(executor_imbalance(&SparkEventLogBuilder::new().add_executor("1", "a").build()).is_empty());
+    }
+
+    #[test]
+    fn flags_shuffle_partitions_well_above_observed() {
+        let mut info = ShufflePartitionInfo { configured: 200, observed: Some(20), aqe_enabled: false, aqe_coalesced: None };
+        assert!(info.is_oversized());
+
+        info.observed = Some(100);
+        assert!(!info.is_oversized());
+
+        info.observed = None;
+        assert!(!info.is_oversized());
+    }
+
+    #[test]
+    fn extracts_yarn_info_only_on_yarn() {
+        let env = environment(&[
+            ("spark.master", "yarn"),
+            ("spark.yarn.queue", "production"),
+            ("spark.yarn.tags", "etl, nightly,"),
+            ("spark.yarn.maxAppAttempts", "2"),
+        ]);
+        let info = extract_yarn_info(&env).unwrap();
+        assert_eq!(info.queue.as_deref(), Some("production"));
+        assert_eq!(info.tags, vec!["etl", "nightly"]);
+        assert_eq!(info.max_app_attempts, Some(2));
+
+        assert!(extract_yarn_info(&environment(&[("spark.yarn.queue", "production")])).is_none());
+    }
+}
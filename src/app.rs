@@ -3,75 +3,211 @@ use crossterm::{
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
+use chrono_tz::Tz;
+use crossterm::event::KeyCode;
 use ratatui::{
     backend::CrosstermBackend,
+    layout::Rect,
+    widgets::TableState,
     Terminal,
 };
 use std::io;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 
+/// How long a footer status message such as "Copied!" stays up.
+const STATUS_MESSAGE_TIMEOUT: Duration = Duration::from_secs(2);
+
+use crate::config::Theme;
 use crate::events::{AppEvent, EventHandler};
-use crate::models::SparkEventLog;
-use crate::ui::{AppState, TabIndex, UI};
+use crate::models::{EventLogAggregates, ParseMetadata, SparkEventLog};
+use crate::parser::{self, LogFileEntry, ParseOptions};
+use crate::ui::{AppState, ExecutorsTab, FilePicker, JobsTab, LoadingScreen, SqlDetailPopup, SqlTab, StageDetailPopup, StagesTab, TabIndex, TasksTab, UI};
+
+pub type Tui = Terminal<CrosstermBackend<io::Stdout>>;
+
+pub fn init_terminal() -> Result<Tui> {
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen)?;
+    let backend = CrosstermBackend::new(stdout);
+    Ok(Terminal::new(backend)?)
+}
+
+pub fn restore_terminal(terminal: &mut Tui) -> Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen
+    )?;
+    terminal.show_cursor()?;
+    Ok(())
+}
+
+/// Event logs at least this large are parsed behind a loading screen.
+pub const LARGE_LOG_BYTES: u64 = 100 * 1024 * 1024;
+
+/// Parse `log_path` while drawing a progress bar, so large logs do not look
+/// like a hung terminal.
+pub fn parse_with_progress(terminal: &mut Tui, log_path: &Path, options: &ParseOptions, theme: &Theme) -> Result<(SparkEventLog, ParseMetadata)> {
+    parser::parse_event_log_with_progress(log_path, options, |bytes_read, total_bytes| {
+        // A failed redraw only costs the progress display, not the parse
+        let _ = terminal.draw(|f| LoadingScreen::draw(f, log_path, bytes_read, total_bytes, theme));
+    })
+}
+
+/// Show a list of scanned event logs and return the path of the one the
+/// user opens, or `None` if they quit without choosing.
+pub fn pick_log_file(terminal: &mut Tui, entries: Vec<LogFileEntry>, theme: &Theme) -> Result<Option<PathBuf>> {
+    let mut table_state = TableState::default();
+    table_state.select(Some(0));
+
+    let mut event_handler = EventHandler::new(Duration::from_millis(100));
+
+    loop {
+        terminal.draw(|f| {
+            FilePicker::draw(f, &entries, &table_state, theme);
+        })?;
+
+        match event_handler.next_event()? {
+            AppEvent::Key(key) => {
+                let selected = table_state.selected().unwrap_or(0);
+                match key.code {
+                    KeyCode::Char('q') | KeyCode::Esc => return Ok(None),
+                    KeyCode::Up | KeyCode::Char('k') if selected > 0 => {
+                        table_state.select(Some(selected - 1));
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if selected + 1 < entries.len() => {
+                        table_state.select(Some(selected + 1));
+                    }
+                    KeyCode::Enter if entries.get(selected).is_some_and(|e| e.result.is_ok()) => {
+                        return Ok(entries.into_iter().nth(selected).map(|e| e.path));
+                    }
+                    _ => {}
+                }
+            }
+            AppEvent::Quit => return Ok(None),
+            // Only App::run raises errors as events
+            AppEvent::Error(_) | AppEvent::Tick | AppEvent::Resize(..) => {}
+        }
+    }
+}
 
 pub struct App {
+    /// The attempt being shown.
     event_log: SparkEventLog,
+    /// Every application attempt by index; the shown one's slot is empty
+    /// while it lives in `event_log`.
+    attempt_logs: Vec<Option<SparkEventLog>>,
+    metadata: ParseMetadata,
+    aggregates: EventLogAggregates,
+    /// Last known terminal area, for sizing popup scrolling.
+    terminal_area: Rect,
     should_quit: bool,
     ui: UI,
     state: AppState,
+    /// Kept alive so copied text stays available on X11, where the
+    /// clipboard owner must outlive the copy.
+    clipboard: Option<arboard::Clipboard>,
 }
 
 impl App {
-    pub fn new(event_log: SparkEventLog) -> Self {
+    pub fn new(mut event_log: SparkEventLog, metadata: ParseMetadata, theme: Theme, display_timezone: Tz, zebra_stripe: bool) -> Self {
+        let mut state = AppState::new();
+        state.theme = theme;
+        state.display_timezone = display_timezone;
+        state.zebra_stripe = zebra_stripe;
+        // Start on the latest attempt, which is what the parser returns
+        let mut attempt_logs: Vec<_> = std::mem::take(&mut event_log.previous_attempts).into_iter().map(Some).collect();
+        state.selected_attempt = attempt_logs.len();
+        attempt_logs.push(None);
+
+        let aggregates = event_log.precompute_aggregates();
+
         Self {
             event_log,
+            attempt_logs,
+            metadata,
+            aggregates,
+            terminal_area: Rect::default(),
             should_quit: false,
             ui: UI::new(),
-            state: AppState::new(),
+            state,
+            clipboard: None,
         }
     }
 
-    pub fn run(&mut self) -> Result<()> {
-        // Setup terminal
-        enable_raw_mode()?;
-        let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)?;
-        let backend = CrosstermBackend::new(stdout);
-        let mut terminal = Terminal::new(backend)?;
-
+    pub fn run(&mut self, terminal: &mut Tui) -> Result<()> {
         // Event handler
         let mut event_handler = EventHandler::new(Duration::from_millis(100));
 
         // Main loop
         while !self.should_quit {
             // Draw UI
-            terminal.draw(|f| {
-                self.ui.draw(f, &self.event_log, &self.state);
+            let frame = terminal.draw(|f| {
+                self.ui.draw(f, &self.event_log, &self.metadata, &self.aggregates, &self.state);
             })?;
+            self.terminal_area = frame.area;
 
-            // Handle events
-            match event_handler.next()? {
+            // Handle events; failures are shown in a popup instead of ending the session
+            let event = match event_handler.next_event() {
+                Ok(event) => event,
+                // Failing again before the first error was dismissed means the terminal is gone
+                Err(error) if self.state.error_message.is_some() => return Err(error),
+                Err(error) => AppEvent::Error(format!("Failed to read terminal event: {}", error)),
+            };
+            match event {
                 AppEvent::Key(key) => self.handle_key_event(key),
                 AppEvent::Quit => self.should_quit = true,
+                AppEvent::Resize(width, height) => {
+                    // Redraw at the new size right away rather than waiting for the next tick
+                    if let Err(error) = terminal.resize(Rect::new(0, 0, width, height)) {
+                        self.state.error_message = Some(format!("Failed to resize: {}", error));
+                        continue;
+                    }
+                    terminal.draw(|f| {
+                        self.ui.draw(f, &self.event_log, &self.metadata, &self.aggregates, &self.state);
+                    })?;
+                }
+                AppEvent::Error(message) => self.state.error_message = Some(message),
                 AppEvent::Tick => {
-                    // Handle periodic updates if needed
+                    if self.state.status_message.as_ref().is_some_and(|(_, shown_at)| shown_at.elapsed() >= STATUS_MESSAGE_TIMEOUT) {
+                        self.state.status_message = None;
+                    }
                 }
             }
         }
 
-        // Cleanup terminal
-        disable_raw_mode()?;
-        execute!(
-            terminal.backend_mut(),
-            LeaveAlternateScreen
-        )?;
-        terminal.show_cursor()?;
-
         Ok(())
     }
 
     fn handle_key_event(&mut self, key: crossterm::event::KeyEvent) {
-        use crossterm::event::KeyCode;
+        // Any key dismisses an error without acting on the key
+        if self.state.error_message.take().is_some() {
+            return;
+        }
+
+        if self.state.warnings_open {
+            self.handle_warnings_key_event(key);
+            return;
+        }
+
+        if self.state.detail_open {
+            match key.code {
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => {
+                    self.state.detail_open = false;
+                }
+                KeyCode::Char('s') if self.state.selected_tab == TabIndex::Jobs => {
+                    self.jump_to_job_sql();
+                }
+                KeyCode::Char('p') if self.state.selected_tab == TabIndex::Sql => {
+                    self.state.show_initial_plan = !self.state.show_initial_plan;
+                }
+                _ if matches!(self.state.selected_tab, TabIndex::Sql | TabIndex::Stages) => self.handle_detail_scroll(key.code),
+                _ => {}
+            }
+            return;
+        }
 
         match key.code {
             KeyCode::Char('q') | KeyCode::Esc => {
@@ -95,6 +231,46 @@ impl App {
             KeyCode::Char('6') => {
                 self.state.selected_tab = TabIndex::Environment;
             }
+            KeyCode::Char('7') => {
+                self.state.selected_tab = TabIndex::Summary;
+            }
+            KeyCode::Enter if self.selected_tab_has_details() => {
+                self.state.detail_open = true;
+                self.state.detail_scroll = 0;
+                self.state.show_initial_plan = false;
+            }
+            KeyCode::Char('s') if self.state.selected_tab == TabIndex::Jobs => {
+                self.jump_to_job_sql();
+            }
+            KeyCode::Char('\'') => {
+                self.copy_selected_value();
+            }
+            KeyCode::Char('t') if self.state.selected_tab == TabIndex::Executors => {
+                self.state.executor_timeline = !self.state.executor_timeline;
+            }
+            KeyCode::Char('f') if matches!(
+                self.state.selected_tab,
+                TabIndex::Jobs | TabIndex::Stages | TabIndex::Tasks | TabIndex::Executors
+            ) => {
+                self.state.show_failed_only = !self.state.show_failed_only;
+                // Row indices change meaning under the filter
+                self.state.jobs_table_state.select(Some(0));
+                self.state.stages_table_state.select(Some(0));
+                self.state.tasks_table_state.select(Some(0));
+                self.state.executors_table_state.select(Some(0));
+            }
+            KeyCode::Char('m') if self.state.selected_tab == TabIndex::Environment => {
+                self.state.show_misc_processes = !self.state.show_misc_processes;
+            }
+            KeyCode::Char('z') => {
+                self.state.zebra_stripe = !self.state.zebra_stripe;
+            }
+            KeyCode::Char('w') => {
+                self.state.warnings_open = true;
+            }
+            KeyCode::Char('A') => {
+                self.switch_to_next_attempt();
+            }
             KeyCode::Tab => {
                 self.state.next_tab();
             }
@@ -103,35 +279,25 @@ impl App {
             }
             KeyCode::Up | KeyCode::Char('k') => {
                 match self.state.selected_tab {
-                    TabIndex::Jobs => {
-                        if self.state.jobs_table_state.selected().unwrap_or(0) > 0 {
-                            let selected = self.state.jobs_table_state.selected().unwrap_or(0);
-                            self.state.jobs_table_state.select(Some(selected - 1));
-                        }
+                    TabIndex::Jobs if self.state.jobs_table_state.selected().unwrap_or(0) > 0 => {
+                        let selected = self.state.jobs_table_state.selected().unwrap_or(0);
+                        self.state.jobs_table_state.select(Some(selected - 1));
                     }
-                    TabIndex::Stages => {
-                        if self.state.stages_table_state.selected().unwrap_or(0) > 0 {
-                            let selected = self.state.stages_table_state.selected().unwrap_or(0);
-                            self.state.stages_table_state.select(Some(selected - 1));
-                        }
+                    TabIndex::Stages if self.state.stages_table_state.selected().unwrap_or(0) > 0 => {
+                        let selected = self.state.stages_table_state.selected().unwrap_or(0);
+                        self.state.stages_table_state.select(Some(selected - 1));
                     }
-                    TabIndex::Tasks => {
-                        if self.state.tasks_table_state.selected().unwrap_or(0) > 0 {
-                            let selected = self.state.tasks_table_state.selected().unwrap_or(0);
-                            self.state.tasks_table_state.select(Some(selected - 1));
-                        }
+                    TabIndex::Tasks if self.state.tasks_table_state.selected().unwrap_or(0) > 0 => {
+                        let selected = self.state.tasks_table_state.selected().unwrap_or(0);
+                        self.state.tasks_table_state.select(Some(selected - 1));
                     }
-                    TabIndex::Executors => {
-                        if self.state.executors_table_state.selected().unwrap_or(0) > 0 {
-                            let selected = self.state.executors_table_state.selected().unwrap_or(0);
-                            self.state.executors_table_state.select(Some(selected - 1));
-                        }
+                    TabIndex::Executors if self.state.executors_table_state.selected().unwrap_or(0) > 0 => {
+                        let selected = self.state.executors_table_state.selected().unwrap_or(0);
+                        self.state.executors_table_state.select(Some(selected - 1));
                     }
-                    TabIndex::Sql => {
-                        if self.state.sql_table_state.selected().unwrap_or(0) > 0 {
-                            let selected = self.state.sql_table_state.selected().unwrap_or(0);
-                            self.state.sql_table_state.select(Some(selected - 1));
-                        }
+                    TabIndex::Sql if self.state.sql_table_state.selected().unwrap_or(0) > 0 => {
+                        let selected = self.state.sql_table_state.selected().unwrap_or(0);
+                        self.state.sql_table_state.select(Some(selected - 1));
                     }
                     _ => {}
                 }
@@ -139,7 +305,7 @@ impl App {
             KeyCode::Down | KeyCode::Char('j') => {
                 match self.state.selected_tab {
                     TabIndex::Jobs => {
-                        let jobs_count = self.event_log.jobs.len();
+                        let jobs_count = JobsTab::sorted_jobs(&self.event_log, self.state.show_failed_only).len();
                         if jobs_count > 0 {
                             let selected = self.state.jobs_table_state.selected().unwrap_or(0);
                             if selected < jobs_count - 1 {
@@ -148,7 +314,7 @@ impl App {
                         }
                     }
                     TabIndex::Stages => {
-                        let stages_count = self.event_log.stages.len();
+                        let stages_count = StagesTab::sorted_stages(&self.event_log, self.state.show_failed_only).len();
                         if stages_count > 0 {
                             let selected = self.state.stages_table_state.selected().unwrap_or(0);
                             if selected < stages_count - 1 {
@@ -157,7 +323,7 @@ impl App {
                         }
                     }
                     TabIndex::Tasks => {
-                        let tasks_count = self.event_log.tasks.len();
+                        let tasks_count = TasksTab::sorted_tasks(&self.event_log, &self.aggregates, self.state.show_failed_only).len();
                         if tasks_count > 0 {
                             let selected = self.state.tasks_table_state.selected().unwrap_or(0);
                             if selected < tasks_count - 1 {
@@ -166,7 +332,7 @@ impl App {
                         }
                     }
                     TabIndex::Executors => {
-                        let executors_count = self.event_log.executors.len();
+                        let executors_count = ExecutorsTab::sorted_executors(&self.event_log, &self.aggregates, self.state.show_failed_only).len();
                         if executors_count > 0 {
                             let selected = self.state.executors_table_state.selected().unwrap_or(0);
                             if selected < executors_count - 1 {
@@ -189,4 +355,185 @@ impl App {
             _ => {}
         }
     }
-}
\ No newline at end of file
+
+    /// Whether the current tab has a detail popup and a row to show it for.
+    fn selected_tab_has_details(&self) -> bool {
+        match self.state.selected_tab {
+            TabIndex::Jobs => !JobsTab::sorted_jobs(&self.event_log, self.state.show_failed_only).is_empty(),
+            TabIndex::Stages => !StagesTab::sorted_stages(&self.event_log, self.state.show_failed_only).is_empty(),
+            TabIndex::Tasks => !TasksTab::sorted_tasks(&self.event_log, &self.aggregates, self.state.show_failed_only).is_empty(),
+            TabIndex::Executors => !ExecutorsTab::sorted_executors(&self.event_log, &self.aggregates, self.state.show_failed_only).is_empty(),
+            TabIndex::Sql => !self.event_log.sql_executions.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// Value of the selected row's primary column: the job description,
+    /// stage name, task failure reason, executor host or SQL description.
+    fn selected_primary_value(&self) -> Option<String> {
+        let failed_only = self.state.show_failed_only;
+        match self.state.selected_tab {
+            TabIndex::Jobs => {
+                let jobs = JobsTab::sorted_jobs(&self.event_log, failed_only);
+                let job = jobs.get(self.state.jobs_table_state.selected()?)?;
+                Some(job.description.clone().unwrap_or_else(|| job.name.clone()))
+            }
+            TabIndex::Stages => {
+                let stages = StagesTab::sorted_stages(&self.event_log, failed_only);
+                Some(stages.get(self.state.stages_table_state.selected()?)?.name.clone())
+            }
+            TabIndex::Tasks => {
+                let tasks = TasksTab::sorted_tasks(&self.event_log, &self.aggregates, failed_only);
+                tasks.get(self.state.tasks_table_state.selected()?)?.failure_reason.clone()
+            }
+            TabIndex::Executors => {
+                let executors = ExecutorsTab::sorted_executors(&self.event_log, &self.aggregates, failed_only);
+                Some(executors.get(self.state.executors_table_state.selected()?)?.host.clone())
+            }
+            TabIndex::Sql => {
+                let executions = SqlTab::sorted_executions(&self.event_log);
+                Some(executions.get(self.state.sql_table_state.selected()?)?.description.clone())
+            }
+            TabIndex::Environment | TabIndex::Summary => None,
+        }
+    }
+
+    fn copy_selected_value(&mut self) {
+        let Some(value) = self.selected_primary_value() else {
+            self.state.set_status_message("Nothing to copy");
+            return;
+        };
+
+        if self.clipboard.is_none() {
+            match arboard::Clipboard::new() {
+                Ok(clipboard) => self.clipboard = Some(clipboard),
+                Err(error) => {
+                    self.state.set_status_message(format!("Copy failed: {}", error));
+                    return;
+                }
+            }
+        }
+
+        let Some(clipboard) = self.clipboard.as_mut() else {
+            return;
+        };
+        match clipboard.set_text(value) {
+            Ok(()) => self.state.set_status_message("Copied!"),
+            Err(error) => self.state.set_status_message(format!("Copy failed: {}", error)),
+        }
+    }
+
+    /// Show the next application attempt's jobs, stages and executors.
+    fn switch_to_next_attempt(&mut self) {
+        let current = self.state.selected_attempt;
+        self.state.next_attempt(self.attempt_logs.len());
+        let Some(next_log) = self.attempt_logs.get_mut(self.state.selected_attempt).and_then(Option::take) else {
+            self.state.selected_attempt = current;
+            return;
+        };
+
+        self.attempt_logs[current] = Some(std::mem::replace(&mut self.event_log, next_log));
+        self.aggregates = self.event_log.precompute_aggregates();
+        // Row indices belong to the previous attempt
+        self.state.jobs_table_state.select(Some(0));
+        self.state.stages_table_state.select(Some(0));
+        self.state.tasks_table_state.select(Some(0));
+        self.state.executors_table_state.select(Some(0));
+        self.state.sql_table_state.select(Some(0));
+    }
+
+    /// Switch to the SQL tab with the selected job's SQL execution selected.
+    fn jump_to_job_sql(&mut self) {
+        let jobs = JobsTab::sorted_jobs(&self.event_log, self.state.show_failed_only);
+        let Some(job) = self.state.jobs_table_state.selected().and_then(|i| jobs.get(i)) else {
+            return;
+        };
+        let Some(execution_id) = self.event_log.sql_execution_for_job(job.job_id).map(|e| e.execution_id) else {
+            return;
+        };
+
+        let executions = SqlTab::sorted_executions(&self.event_log);
+        let index = executions.iter().position(|e| e.execution_id == execution_id);
+        self.state.sql_table_state.select(index);
+        self.state.selected_tab = TabIndex::Sql;
+        self.state.detail_open = false;
+    }
+
+    fn handle_detail_scroll(&mut self, code: KeyCode) {
+        let area = self.terminal_area;
+        let theme = &self.state.theme;
+        let (max_scroll, page) = match self.state.selected_tab {
+            TabIndex::Sql => {
+                let executions = SqlTab::sorted_executions(&self.event_log);
+                let Some(execution) = self.state.sql_table_state.selected().and_then(|i| executions.get(i)) else {
+                    return;
+                };
+                let max_scroll = SqlDetailPopup::max_scroll(execution, area, self.state.show_initial_plan, self.state.display_timezone, theme);
+                (max_scroll, SqlDetailPopup::page_height(area))
+            }
+            TabIndex::Stages => {
+                let stages = StagesTab::sorted_stages(&self.event_log, self.state.show_failed_only);
+                let Some(stage) = self.state.stages_table_state.selected().and_then(|i| stages.get(i)) else {
+                    return;
+                };
+                (StageDetailPopup::max_scroll(stage, area, theme), StageDetailPopup::page_height(area))
+            }
+            _ => return,
+        };
+        let page = page.max(1);
+        let scroll = self.state.detail_scroll.min(max_scroll);
+
+        self.state.detail_scroll = match code {
+            KeyCode::Up | KeyCode::Char('k') => scroll.saturating_sub(1),
+            KeyCode::Down | KeyCode::Char('j') => scroll.saturating_add(1).min(max_scroll),
+            KeyCode::PageUp => scroll.saturating_sub(page),
+            KeyCode::PageDown => scroll.saturating_add(page).min(max_scroll),
+            KeyCode::Home | KeyCode::Char('g') => 0,
+            KeyCode::End | KeyCode::Char('G') => max_scroll,
+            _ => scroll,
+        };
+    }
+
+    fn handle_warnings_key_event(&mut self, key: crossterm::event::KeyEvent) {
+        let selected = self.state.warnings_table_state.selected().unwrap_or(0);
+
+        match key.code {
+            KeyCode::Char('w') | KeyCode::Esc | KeyCode::Char('q') => {
+                self.state.warnings_open = false;
+            }
+            KeyCode::Up | KeyCode::Char('k') if selected > 0 => {
+                self.state.warnings_table_state.select(Some(selected - 1));
+            }
+            KeyCode::Down | KeyCode::Char('j') if selected + 1 < self.event_log.parse_warnings.len() => {
+                self.state.warnings_table_state.select(Some(selected + 1));
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SparkEventLogBuilder;
+    use crossterm::event::{KeyEvent, KeyModifiers};
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn any_key_dismisses_error_without_acting_on_it() {
+        let event_log = SparkEventLogBuilder::new().add_executor("1", "worker-a").build();
+        let metadata = ParseMetadata { file_size_bytes: 0, line_count: 0 };
+        let mut app = App::new(event_log, metadata, Theme::default(), Tz::UTC, false);
+
+        app.state.error_message = Some("Failed to read terminal event: broken pipe".to_string());
+        press(&mut app, KeyCode::Char('q'));
+        assert_eq!(app.state.error_message, None);
+        assert!(!app.should_quit);
+
+        press(&mut app, KeyCode::Char('2'));
+        assert_eq!(app.state.selected_tab, TabIndex::Stages);
+    }
+}
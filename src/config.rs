@@ -0,0 +1,190 @@
+use anyhow::{Context, Result};
+use ratatui::style::Color;
+use serde::Deserialize;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// Colors used across the UI, so every tab draws with the same palette.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    pub header_fg: Color,
+    pub tab_active_bg: Color,
+    pub tab_active_fg: Color,
+    pub status_success: Color,
+    pub status_failed: Color,
+    pub status_running: Color,
+    /// Warnings that are not failures, e.g. skew or a large closure.
+    pub status_warning: Color,
+    pub status_killed: Color,
+    /// Highlights that are neither a status nor a warning, e.g. memory sparklines
+    /// and unschedulable stages.
+    pub accent: Color,
+    /// Labels in detail popups.
+    pub label_fg: Color,
+    pub row_highlight_bg: Color,
+    pub row_highlight_fg: Color,
+    pub zebra_stripe_bg: Color,
+    pub text_fg: Color,
+    pub muted_fg: Color,
+    pub background: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self {
+            header_fg: Color::Yellow,
+            tab_active_bg: Color::Blue,
+            tab_active_fg: Color::White,
+            status_success: Color::Green,
+            status_failed: Color::Red,
+            status_running: Color::Blue,
+            status_warning: Color::Yellow,
+            status_killed: Color::Magenta,
+            accent: Color::Magenta,
+            label_fg: Color::Cyan,
+            row_highlight_bg: Color::DarkGray,
+            row_highlight_fg: Color::Reset,
+            zebra_stripe_bg: Color::Rgb(30, 30, 30),
+            text_fg: Color::White,
+            muted_fg: Color::Gray,
+            background: Color::Reset,
+        }
+    }
+
+    pub fn light() -> Self {
+        Self {
+            header_fg: Color::Blue,
+            tab_active_bg: Color::Blue,
+            tab_active_fg: Color::White,
+            status_success: Color::Green,
+            status_failed: Color::Red,
+            status_running: Color::Blue,
+            status_warning: Color::Rgb(175, 95, 0),
+            status_killed: Color::Magenta,
+            accent: Color::Magenta,
+            label_fg: Color::Rgb(0, 95, 135),
+            row_highlight_bg: Color::Rgb(200, 200, 200),
+            row_highlight_fg: Color::Black,
+            zebra_stripe_bg: Color::Rgb(235, 235, 235),
+            text_fg: Color::Black,
+            muted_fg: Color::DarkGray,
+            background: Color::White,
+        }
+    }
+
+    /// Pure black and white, with bright status colors and no grays. Striped
+    /// rows are navy, which stays distinct from the black background.
+    pub fn high_contrast() -> Self {
+        Self {
+            header_fg: Color::White,
+            tab_active_bg: Color::White,
+            tab_active_fg: Color::Black,
+            status_success: Color::LightGreen,
+            status_failed: Color::LightRed,
+            status_running: Color::LightCyan,
+            status_warning: Color::LightYellow,
+            status_killed: Color::LightMagenta,
+            accent: Color::LightMagenta,
+            label_fg: Color::White,
+            row_highlight_bg: Color::White,
+            row_highlight_fg: Color::Black,
+            zebra_stripe_bg: Color::Rgb(0, 0, 95),
+            text_fg: Color::White,
+            muted_fg: Color::White,
+            background: Color::Black,
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl FromStr for Theme {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dark" => Ok(Theme::dark()),
+            "light" => Ok(Theme::light()),
+            "high-contrast" => Ok(Theme::high_contrast()),
+            _ => Err(format!("unrecognized theme '{}' (supported: dark, light, high-contrast)", s)),
+        }
+    }
+}
+
+/// Settings read from `$XDG_CONFIG_HOME/spark-tui/config.toml`
+/// (or `~/.config/spark-tui/config.toml`).
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    pub theme: Option<String>,
+    /// Whether tables start with alternate rows shaded; `z` still toggles it.
+    pub zebra_stripe: Option<bool>,
+}
+
+impl Config {
+    pub fn path() -> Option<PathBuf> {
+        let config_dir = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+        Some(config_dir.join("spark-tui").join("config.toml"))
+    }
+
+    /// Reads the config file, or the defaults when there is none.
+    pub fn load() -> Result<Self> {
+        match Self::path() {
+            Some(path) if path.exists() => {
+                let contents = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read config file {}", path.display()))?;
+                toml::from_str(&contents)
+                    .with_context(|| format!("Failed to parse config file {}", path.display()))
+            }
+            _ => Ok(Self::default()),
+        }
+    }
+
+    /// The theme to draw with: `cli_theme` wins over the config file, which
+    /// wins over the dark default.
+    pub fn resolve_theme(&self, cli_theme: Option<Theme>) -> Result<Theme> {
+        if let Some(theme) = cli_theme {
+            return Ok(theme);
+        }
+        match &self.theme {
+            Some(name) => name.parse().map_err(|e: String| anyhow::anyhow!("{} in config file", e)),
+            None => Ok(Theme::default()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cli_theme_overrides_config_file() {
+        let config: Config = toml::from_str(r#"theme = "light""#).unwrap();
+        assert_eq!(config.resolve_theme(None).unwrap(), Theme::light());
+        assert_eq!(config.resolve_theme(Some(Theme::high_contrast())).unwrap(), Theme::high_contrast());
+        assert_eq!(Config::default().resolve_theme(None).unwrap(), Theme::dark());
+
+        let bad: Config = toml::from_str(r#"theme = "neon""#).unwrap();
+        assert!(bad.resolve_theme(None).is_err());
+    }
+
+    #[test]
+    fn zebra_stripes_stand_out_from_the_background() {
+        for theme in [Theme::dark(), Theme::light(), Theme::high_contrast()] {
+            assert_ne!(theme.zebra_stripe_bg, theme.background);
+        }
+    }
+
+    #[test]
+    fn reads_zebra_stripe_setting() {
+        let config: Config = toml::from_str("zebra_stripe = false").unwrap();
+        assert_eq!(config.zebra_stripe, Some(false));
+        assert_eq!(Config::default().zebra_stripe, None);
+    }
+}
@@ -5,7 +5,12 @@ use std::time::{Duration, Instant};
 pub enum AppEvent {
     Key(KeyEvent),
     Tick,
+    Resize(u16, u16),
     Quit,
+    /// Something went wrong outside key handling; shown to the user rather
+    /// than ending the session. `App::run` raises it when reading a terminal
+    /// event fails.
+    Error(String),
 }
 
 pub struct EventHandler {
@@ -21,7 +26,7 @@ impl EventHandler {
         }
     }
 
-    pub fn next(&mut self) -> anyhow::Result<AppEvent> {
+    pub fn next_event(&mut self) -> anyhow::Result<AppEvent> {
         let timeout = self.tick_rate
             .checked_sub(self.last_tick.elapsed())
             .unwrap_or_else(|| Duration::from_secs(0));
@@ -34,6 +39,7 @@ impl EventHandler {
                     }
                     Ok(AppEvent::Key(key))
                 }
+                Event::Resize(width, height) => Ok(AppEvent::Resize(width, height)),
                 _ => Ok(AppEvent::Tick),
             }
         } else {
@@ -0,0 +1,9 @@
+pub mod analysis;
+pub mod app;
+pub mod config;
+pub mod events;
+pub mod models;
+pub mod parser;
+pub mod report;
+pub mod ui;
+pub mod util;
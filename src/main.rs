@@ -1,51 +1,234 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
 use clap::Parser;
 use std::path::PathBuf;
 
-mod app;
-mod events;
-mod models;
-mod parser;
-mod ui;
-
-use app::App;
+use spark_tui::app::{self, App};
+use spark_tui::config::{Config, Theme};
+use spark_tui::{parser, report, ui};
 
 #[derive(Parser)]
 #[command(name = "spark-tui")]
 #[command(about = "Terminal UI for Apache Spark event log analysis")]
 struct Args {
     /// Path to the Spark event log file
-    #[arg(short, long)]
-    log_file: PathBuf,
-    
+    #[arg(short, long, required_unless_present = "log_dir", conflicts_with = "log_dir")]
+    log_file: Option<PathBuf>,
+
+    /// Directory of event logs to choose from
+    #[arg(long)]
+    log_dir: Option<PathBuf>,
+
+    /// Also scan subdirectories of --log-dir
+    #[arg(short, long, requires = "log_dir")]
+    recursive: bool,
+
+    /// Treat --log-file as a rolling log and read its numbered siblings too
+    #[arg(long, conflicts_with = "log_dir")]
+    rolling: bool,
+
+    /// Only keep jobs, stages and tasks that started at or after this
+    /// RFC 3339 timestamp (e.g. 2024-12-01T12:00:00Z)
+    #[arg(long, value_name = "TIMESTAMP", conflicts_with = "validate")]
+    since: Option<DateTime<Utc>>,
+
+    /// Check the event log for malformed or out-of-order events and internal
+    /// inconsistencies, print each one and exit with status 1 if any are found
+    #[arg(long, conflicts_with_all = ["log_dir", "test"])]
+    validate: bool,
+
     /// Test mode - just parse and print summary without starting TUI
     #[arg(short, long)]
     test: bool,
+
+    /// In test mode, print a single pipe-delimited line instead of the summary:
+    /// "<app name> | <app id> | Jobs: N | Failed: N | Duration: 45.2s"
+    #[arg(short, long, requires = "test", conflicts_with = "sort_by")]
+    quiet: bool,
+
+    /// In test mode, also print a table sorted by <table>:<column>, descending
+    /// (jobs:duration, jobs:status, stages:duration, stages:num_tasks,
+    /// tasks:duration, tasks:gc_time, executors:total_tasks)
+    #[arg(long, value_name = "COLUMN", requires = "test")]
+    sort_by: Option<report::SortKey>,
+
+    /// In test mode, print the summary as text, json or csv
+    #[arg(long, value_name = "FORMAT", default_value = "text", requires = "test", conflicts_with_all = ["quiet", "sort_by"])]
+    output_format: report::OutputFormat,
+
+    /// Color theme: dark, light or high-contrast. Overrides `theme` in
+    /// ~/.config/spark-tui/config.toml
+    #[arg(long, value_name = "NAME")]
+    theme: Option<Theme>,
+
+    /// IANA timezone to show timestamps in (e.g. America/New_York)
+    #[arg(long, value_name = "TZ", default_value = "UTC")]
+    timezone: Tz,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
-    
+    let config = Config::load()?;
+    let theme = config.resolve_theme(args.theme)?;
+    let zebra_stripe = config.zebra_stripe.unwrap_or(true);
+    let parse_options = parser::ParseOptions { rolling: args.rolling, since: args.since };
+
+    if let Some(log_dir) = &args.log_dir {
+        let entries = parser::scan_log_dir(log_dir, args.recursive, &parse_options)?;
+
+        if args.test {
+            // Test mode - print one line per file
+            for entry in &entries {
+                match &entry.result {
+                    Ok(summary) => println!(
+                        "{}: {} ({}) | Jobs: {}",
+                        entry.path.display(),
+                        summary.app_name,
+                        summary.app_id,
+                        summary.job_count
+                    ),
+                    Err(error) => println!("{}: WARNING skipped ({})", entry.path.display(), error),
+                }
+            }
+            return Ok(());
+        }
+
+        let mut terminal = app::init_terminal()?;
+        // Only the chosen log is parsed in full, behind the loading screen
+        let result = app::pick_log_file(&mut terminal, entries, &theme).and_then(|picked| match picked {
+            Some(path) => {
+                let (event_log, metadata) = app::parse_with_progress(&mut terminal, &path, &parse_options, &theme)?;
+                App::new(event_log, metadata, theme, args.timezone, zebra_stripe).run(&mut terminal)
+            }
+            None => Ok(()),
+        });
+        app::restore_terminal(&mut terminal)?;
+        return result;
+    }
+
+    let log_file = args.log_file.expect("clap requires --log-file without --log-dir");
+
+    // Large logs going to the TUI get a loading screen, so the terminal is set up before parsing
+    let shows_tui = !args.test && !args.validate;
+    let is_large = parser::event_log_size(&log_file, &parse_options).is_ok_and(|size| size >= app::LARGE_LOG_BYTES);
+    let mut terminal = None;
+
     // Parse the event log
-    let event_log = parser::parse_event_log(&args.log_file)?;
-    
+    let (event_log, metadata) = if shows_tui && is_large {
+        let tui = terminal.insert(app::init_terminal()?);
+        match app::parse_with_progress(tui, &log_file, &parse_options, &theme) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                app::restore_terminal(tui)?;
+                return Err(error);
+            }
+        }
+    } else {
+        parser::parse_event_log_with_options(&log_file, &parse_options)?
+    };
+
+    if args.validate {
+        let mut issues: Vec<String> = event_log.parse_warnings.iter()
+            .map(|warning| format!("Line {}: {}", warning.line_number, warning.error))
+            .collect();
+        issues.extend(parser::validation::validate_event_log(&event_log));
+        for issue in &issues {
+            println!("WARNING: {}", issue);
+        }
+        // Concurrent queries log out of order legitimately, so these do not fail validation
+        for warning in &event_log.ordering_warnings {
+            println!("ORDERING: {}", warning);
+        }
+        if !issues.is_empty() {
+            std::process::exit(1);
+        }
+        println!("No consistency issues found in {}", log_file.display());
+        return Ok(());
+    }
+
     if args.test {
         // Test mode - print summary
-        println!("Successfully parsed event log: {}", args.log_file.display());
-        println!("Application: {} ({})", event_log.application_info.app_name, event_log.application_info.app_id);
-        println!("User: {}, Spark Version: {}", event_log.application_info.user, event_log.application_info.spark_version);
-        println!("Jobs: {}", event_log.jobs.len());
-        println!("Stages: {}", event_log.stages.len());
-        println!("Tasks: {}", event_log.tasks.len());
+        let summary = event_log.summary();
+        let duration = summary.duration_ms
+            .map(ui::format_duration)
+            .unwrap_or_else(|| "Running".to_string());
+
+        match args.output_format {
+            report::OutputFormat::Text => {}
+            report::OutputFormat::Json => {
+                println!("{}", serde_json::to_string_pretty(&summary)?);
+                return Ok(());
+            }
+            report::OutputFormat::Csv => {
+                println!("{}", report::summary_csv(&summary));
+                return Ok(());
+            }
+        }
+
+        if args.quiet {
+            println!(
+                "{} | {} | Jobs: {} | Failed: {} | Duration: {}",
+                summary.app_name, summary.app_id, summary.total_jobs, summary.failed_jobs, duration
+            );
+            return Ok(());
+        }
+
+        println!("Successfully parsed event log: {}", log_file.display());
+        println!("File size: {} | Lines: {}", ui::format_bytes(metadata.file_size_bytes), ui::format_count(metadata.line_count));
+        let app_info = &event_log.application_info;
+        match &app_info.app_attempt_id {
+            Some(attempt_id) => println!("Application: {} ({}, Attempt {})", app_info.app_name, app_info.app_id, attempt_id),
+            None => println!("Application: {} ({})", app_info.app_name, app_info.app_id),
+        }
+        println!("User: {}, Spark Version: {}", app_info.user, app_info.spark_version);
+        println!("Duration: {}", duration);
+        if event_log.application_attempts.len() > 1 {
+            println!("Attempts: {}", event_log.application_attempts.len());
+        }
+        println!("Jobs: {}", summary.total_jobs);
+        println!("Stages: {}", summary.total_stages);
+        println!("Tasks: {}", summary.total_tasks);
+        println!("Failed: Jobs {} | Stages {} | Tasks {}", summary.failed_jobs, summary.failed_stages, summary.failed_tasks);
+        println!(
+            "Executor Time: {} | CPU Time: {} | GC Time: {} ({:.1}%)",
+            ui::format_duration(summary.total_executor_time_ms),
+            ui::format_duration(summary.total_executor_cpu_time_ms),
+            ui::format_duration(summary.total_gc_time_ms),
+            summary.gc_overhead_pct
+        );
+        println!(
+            "Input: {} | Output: {} | Shuffle Read: {} | Shuffle Write: {}",
+            ui::format_bytes(summary.total_input_bytes),
+            ui::format_bytes(summary.total_output_bytes),
+            ui::format_bytes(summary.total_shuffle_read_bytes),
+            ui::format_bytes(summary.total_shuffle_write_bytes)
+        );
+        println!(
+            "Spilled: Memory {} | Disk {}",
+            ui::format_bytes(summary.total_memory_spilled_bytes),
+            ui::format_bytes(summary.total_disk_spilled_bytes)
+        );
         println!("Executors: {}", event_log.executors.len());
         println!("SQL Executions: {}", event_log.sql_executions.len());
         println!("Spark Properties: {}", event_log.environment.spark_properties.len());
+        println!("Parse Warnings: {}", event_log.parse_warnings.len());
+
+        if let Some(sort_key) = args.sort_by {
+            println!();
+            report::print_sorted_table(&event_log, sort_key);
+        }
         return Ok(());
     }
-    
+
     // Create and run the TUI app
-    let mut app = App::new(event_log);
-    app.run()?;
-    
-    Ok(())
-}
\ No newline at end of file
+    let mut terminal = match terminal {
+        Some(terminal) => terminal,
+        None => app::init_terminal()?,
+    };
+    let mut app = App::new(event_log, metadata, theme, args.timezone, zebra_stripe);
+    let result = app.run(&mut terminal);
+    app::restore_terminal(&mut terminal)?;
+
+    result
+}
@@ -1,16 +1,266 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SparkEventLog {
     pub application_info: ApplicationInfo,
+    pub application_attempts: Vec<ApplicationInfo>,
     pub jobs: HashMap<u64, Job>,
-    pub stages: HashMap<u64, Stage>,
+    /// Keyed by `(stage_id, stage_attempt_id)` so retried stages keep every attempt.
+    pub stages: HashMap<(u64, u64), Stage>,
     pub tasks: HashMap<u64, Task>,
     pub executors: HashMap<String, Executor>,
+    /// Standalone cluster nodes, keyed by host.
+    pub cluster_nodes: HashMap<String, ClusterNode>,
+    /// Standalone cluster workers, keyed by worker ID.
+    pub workers: HashMap<String, Worker>,
+    pub block_manager_migrations: Vec<BlockManagerMigration>,
+    /// The driver's block manager, which has no executor of its own.
+    pub driver_block_manager: Option<BlockManager>,
+    /// Hosts excluded (blacklisted before Spark 3.1) and when, in log order.
+    pub excluded_nodes: Vec<(String, DateTime<Utc>)>,
+    pub unschedulable_events: Vec<UnschedulableEvent>,
+    pub misc_processes: Vec<MiscProcess>,
+    pub resource_profiles: HashMap<u32, ResourceProfile>,
     pub environment: Environment,
     pub sql_executions: HashMap<u64, SqlExecution>,
+    pub parse_warnings: Vec<ParseWarning>,
+    /// Events that arrived in an order Spark does not usually write them in.
+    /// Concurrent queries cause these in valid logs, so they are kept apart
+    /// from the parse warnings.
+    pub ordering_warnings: Vec<String>,
+    /// Earlier application attempts in the same log (YARN retries), oldest
+    /// first. Each has its own jobs, stages and executors, since IDs start
+    /// over with every attempt.
+    pub previous_attempts: Vec<SparkEventLog>,
+}
+
+impl SparkEventLog {
+    /// Time from application start to end; `None` while it is still running.
+    pub fn total_wall_clock_time(&self) -> Option<chrono::Duration> {
+        self.application_info.end_time.map(|end| end - self.application_info.start_time)
+    }
+
+    /// CPU time of every task that reported metrics, in milliseconds.
+    pub fn total_executor_cpu_time_ms(&self) -> u64 {
+        // Spark reports CPU time in nanoseconds; convert once so per-task
+        // remainders are not dropped
+        let cpu_time_ns: u64 = self.tasks.values().filter_map(|t| t.metrics.as_ref()).map(|m| m.cpu_time).sum();
+        cpu_time_ns / 1_000_000
+    }
+
+    /// Run time of every task that reported metrics, in milliseconds.
+    pub fn total_executor_wall_time_ms(&self) -> u64 {
+        self.tasks.values().filter_map(|t| t.metrics.as_ref()).map(|m| m.execution_time).sum()
+    }
+
+    /// Headline numbers for the whole application, aggregated over every task.
+    pub fn summary(&self) -> ApplicationSummary {
+        let app_info = &self.application_info;
+        let mut summary = ApplicationSummary {
+            app_name: app_info.app_name.clone(),
+            app_id: app_info.app_id.clone(),
+            duration_ms: self.total_wall_clock_time().map(|duration| duration.num_milliseconds().max(0) as u64),
+            total_jobs: self.jobs.len(),
+            failed_jobs: self.jobs.values().filter(|j| matches!(j.status, JobStatus::Failed)).count(),
+            total_stages: self.stages.len(),
+            failed_stages: self.stages.values().filter(|s| matches!(s.status, StageStatus::Failed)).count(),
+            total_tasks: self.tasks.len(),
+            failed_tasks: self.tasks.values().filter(|t| matches!(t.status, TaskStatus::Failed)).count(),
+            total_executor_time_ms: self.total_executor_wall_time_ms(),
+            total_executor_cpu_time_ms: self.total_executor_cpu_time_ms(),
+            ..ApplicationSummary::default()
+        };
+
+        for metrics in self.tasks.values().filter_map(|t| t.metrics.as_ref()) {
+            summary.total_gc_time_ms += metrics.gc_time;
+            summary.total_memory_spilled_bytes += metrics.memory_bytes_spilled;
+            summary.total_disk_spilled_bytes += metrics.disk_bytes_spilled;
+            if let Some(input) = &metrics.input_metrics {
+                summary.total_input_bytes += input.bytes_read;
+            }
+            if let Some(output) = &metrics.output_metrics {
+                summary.total_output_bytes += output.bytes_written;
+            }
+            if let Some(shuffle_read) = &metrics.shuffle_read_metrics {
+                summary.total_shuffle_read_bytes += shuffle_read.local_bytes_read + shuffle_read.remote_bytes_read;
+            }
+            if let Some(shuffle_write) = &metrics.shuffle_write_metrics {
+                summary.total_shuffle_write_bytes += shuffle_write.bytes_written;
+            }
+        }
+
+        if summary.total_executor_time_ms > 0 {
+            summary.gc_overhead_pct = summary.total_gc_time_ms as f64 / summary.total_executor_time_ms as f64 * 100.0;
+        }
+
+        summary
+    }
+
+    /// Tasks of every attempt of a stage, ordered by task ID.
+    pub fn tasks_for_stage(&self, stage_id: u64) -> Vec<&Task> {
+        let mut tasks: Vec<_> = self.tasks.values().filter(|t| t.stage_id == stage_id).collect();
+        tasks.sort_by_key(|t| t.task_id);
+        tasks
+    }
+
+    /// The SQL execution that submitted a job, if any.
+    pub fn sql_execution_for_job(&self, job_id: u64) -> Option<&SqlExecution> {
+        match self.jobs.get(&job_id).and_then(|job| job.sql_execution_id) {
+            Some(execution_id) => self.sql_executions.get(&execution_id),
+            None => self.sql_executions.values().find(|e| e.jobs.contains(&job_id)),
+        }
+    }
+
+    /// Per-stage and per-executor task totals and the other task-derived
+    /// figures the tabs show, computed once so the tabs don't rescan every
+    /// task on each frame.
+    pub fn precompute_aggregates(&self) -> EventLogAggregates {
+        let mut aggregates = EventLogAggregates {
+            app_totals: self.summary(),
+            ..EventLogAggregates::default()
+        };
+
+        for job in self.jobs.values() {
+            let counts = &mut aggregates.job_status_counts;
+            match job.status {
+                JobStatus::Running => counts.running += 1,
+                JobStatus::Succeeded => counts.succeeded += 1,
+                JobStatus::Failed => counts.failed += 1,
+                JobStatus::Unknown => {}
+            }
+        }
+
+        for task in self.tasks.values() {
+            let failed = matches!(task.status, TaskStatus::Failed);
+            let counts = &mut aggregates.task_status_counts;
+            match task.status {
+                TaskStatus::Running => counts.running += 1,
+                TaskStatus::Success => counts.succeeded += 1,
+                TaskStatus::Failed => counts.failed += 1,
+                TaskStatus::Killed => counts.killed += 1,
+            }
+
+            let stage = aggregates.stage_task_counts
+                .entry((task.stage_id, task.stage_attempt_id))
+                .or_default();
+            stage.total_tasks += 1;
+            stage.failed_tasks += failed as usize;
+
+            let executor = aggregates.executor_task_counts
+                .entry(task.executor_id.clone())
+                .or_default();
+            executor.total_tasks += 1;
+            executor.completed_tasks += matches!(task.status, TaskStatus::Success) as usize;
+            executor.failed_tasks += failed as usize;
+
+            if let Some(metrics) = &task.metrics {
+                stage.memory_spilled_bytes += metrics.memory_bytes_spilled;
+                stage.disk_spilled_bytes += metrics.disk_bytes_spilled;
+                executor.records_read += metrics.input_metrics.as_ref().map(|i| i.records_read).unwrap_or(0);
+                executor.run_time_ms += metrics.execution_time;
+
+                if let Some(shuffle_read) = &metrics.shuffle_read_metrics {
+                    let blocks = (shuffle_read.local_blocks_fetched, shuffle_read.remote_blocks_fetched);
+                    aggregates.shuffle_blocks.0 += blocks.0;
+                    aggregates.shuffle_blocks.1 += blocks.1;
+                    if failed {
+                        aggregates.failed_shuffle_blocks.0 += blocks.0;
+                        aggregates.failed_shuffle_blocks.1 += blocks.1;
+                    }
+                }
+            }
+        }
+
+        aggregates.task_order = self.tasks.keys().copied().collect();
+        aggregates.task_order.sort_unstable();
+        for task_id in &aggregates.task_order {
+            let executor_id = &self.tasks[task_id].executor_id;
+            aggregates.executor_tasks.entry(executor_id.clone()).or_default().push(*task_id);
+        }
+
+        aggregates.imbalanced_executors = crate::analysis::executor_imbalance(self)
+            .into_iter()
+            .map(|e| e.executor_id)
+            .collect();
+        aggregates.shuffle_partitions = crate::analysis::compute_shuffle_partition_info(self);
+
+        aggregates
+    }
+}
+
+/// Totals derived from the parsed log, shared by the tabs.
+#[derive(Debug, Clone, Default)]
+pub struct EventLogAggregates {
+    /// Keyed by `(stage_id, stage_attempt_id)`, like `SparkEventLog::stages`.
+    pub stage_task_counts: HashMap<(u64, u64), StageSummary>,
+    pub executor_task_counts: HashMap<String, ExecutorSummary>,
+    pub app_totals: ApplicationSummary,
+    pub job_status_counts: StatusCounts,
+    pub task_status_counts: StatusCounts,
+    /// Every task ID in ascending order, the order the tasks table shows.
+    pub task_order: Vec<u64>,
+    /// IDs of the tasks each executor ran, in ascending order.
+    pub executor_tasks: HashMap<String, Vec<u64>>,
+    /// Executors flagged by `analysis::executor_imbalance`.
+    pub imbalanced_executors: HashSet<String>,
+    /// Local and remote shuffle blocks fetched, over every task.
+    pub shuffle_blocks: (u64, u64),
+    /// Local and remote shuffle blocks fetched by failed tasks, for the failed-only view.
+    pub failed_shuffle_blocks: (u64, u64),
+    pub shuffle_partitions: crate::analysis::ShufflePartitionInfo,
+}
+
+/// How many jobs or tasks ended up in each state.
+#[derive(Debug, Clone, Default)]
+pub struct StatusCounts {
+    pub running: usize,
+    pub succeeded: usize,
+    pub failed: usize,
+    pub killed: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct StageSummary {
+    pub total_tasks: usize,
+    pub failed_tasks: usize,
+    pub memory_spilled_bytes: u64,
+    pub disk_spilled_bytes: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ExecutorSummary {
+    pub total_tasks: usize,
+    pub completed_tasks: usize,
+    pub failed_tasks: usize,
+    pub records_read: u64,
+    pub run_time_ms: u64,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ApplicationSummary {
+    pub app_name: String,
+    pub app_id: String,
+    /// `None` while the application is still running.
+    pub duration_ms: Option<u64>,
+    pub total_jobs: usize,
+    pub failed_jobs: usize,
+    pub total_stages: usize,
+    pub failed_stages: usize,
+    pub total_tasks: usize,
+    pub failed_tasks: usize,
+    pub total_executor_time_ms: u64,
+    pub total_executor_cpu_time_ms: u64,
+    pub total_gc_time_ms: u64,
+    pub gc_overhead_pct: f64,
+    pub total_input_bytes: u64,
+    pub total_output_bytes: u64,
+    pub total_shuffle_read_bytes: u64,
+    pub total_shuffle_write_bytes: u64,
+    pub total_memory_spilled_bytes: u64,
+    pub total_disk_spilled_bytes: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -20,6 +270,8 @@ pub struct ApplicationInfo {
     pub app_attempt_id: Option<String>,
     pub start_time: DateTime<Utc>,
     pub end_time: Option<DateTime<Utc>>,
+    /// Only written by some Spark versions.
+    pub exit_code: Option<i32>,
     pub user: String,
     pub spark_version: String,
 }
@@ -38,6 +290,14 @@ pub struct Job {
     pub num_completed_tasks: u64,
     pub num_skipped_tasks: u64,
     pub num_failed_tasks: u64,
+    /// Tasks per stage ID, first from the job start's `Stage Infos` and
+    /// then from each stage's submission.
+    pub stage_task_counts: HashMap<u64, u64>,
+    pub properties: HashMap<String, String>,
+    /// The SQL execution that submitted the job, from `spark.sql.execution.id`.
+    pub sql_execution_id: Option<u64>,
+    /// The exception message a failed job was aborted with.
+    pub failure_reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -48,6 +308,18 @@ pub enum JobStatus {
     Unknown,
 }
 
+impl fmt::Display for JobStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            JobStatus::Running => "RUNNING",
+            JobStatus::Succeeded => "SUCCEEDED",
+            JobStatus::Failed => "FAILED",
+            JobStatus::Unknown => "UNKNOWN",
+        };
+        f.write_str(text)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Stage {
     pub stage_id: u64,
@@ -60,6 +332,27 @@ pub struct Stage {
     pub completion_time: Option<DateTime<Utc>>,
     pub status: StageStatus,
     pub task_metrics: Option<TaskMetrics>,
+    pub properties: HashMap<String, String>,
+    pub sql_execution_id: Option<u64>,
+    /// Call-site stack trace of the code that created the stage.
+    pub details: String,
+    /// Peak memory of each executor while it ran this stage, keyed by executor ID.
+    pub executor_peak_metrics: HashMap<String, StageExecutorMetrics>,
+    pub failure_reason: Option<String>,
+    /// Serialized size of the task binary sent with every task, when the
+    /// Spark version reports it.
+    pub task_binary_size: Option<u64>,
+}
+
+/// Peak JVM and process memory an executor reached during one stage attempt,
+/// from `SparkListenerStageExecutorMetrics`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StageExecutorMetrics {
+    pub executor_id: String,
+    pub stage_id: u64,
+    pub peak_jvm_used_heap: u64,
+    pub peak_jvm_used_non_heap: u64,
+    pub peak_process_tree_rss_bytes: u64,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -70,17 +363,33 @@ pub enum StageStatus {
     Pending,
 }
 
+impl fmt::Display for StageStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            StageStatus::Active => "ACTIVE",
+            StageStatus::Complete => "COMPLETE",
+            StageStatus::Failed => "FAILED",
+            StageStatus::Pending => "PENDING",
+        };
+        f.write_str(text)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Task {
     pub task_id: u64,
     pub stage_id: u64,
     pub stage_attempt_id: u64,
     pub partition_id: u64,
+    /// Position of the task within its stage attempt (`Task Info.Index`).
+    pub task_index: u64,
     pub executor_id: String,
     pub host: String,
     pub launch_time: DateTime<Utc>,
     pub finish_time: Option<DateTime<Utc>>,
     pub status: TaskStatus,
+    /// Why a failed or killed task ended, from its `Task End Reason`.
+    pub failure_reason: Option<String>,
     pub metrics: Option<TaskMetrics>,
 }
 
@@ -92,7 +401,19 @@ pub enum TaskStatus {
     Killed,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl fmt::Display for TaskStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            TaskStatus::Running => "RUNNING",
+            TaskStatus::Success => "SUCCESS",
+            TaskStatus::Failed => "FAILED",
+            TaskStatus::Killed => "KILLED",
+        };
+        f.write_str(text)
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct TaskMetrics {
     pub execution_time: u64,
     pub cpu_time: u64,
@@ -100,6 +421,10 @@ pub struct TaskMetrics {
     pub result_size: u64,
     pub jvm_gc_time: u64,
     pub result_serialization_time: u64,
+    pub deserialization_time: u64,
+    pub scheduler_delay: u64,
+    /// Time the driver spent fetching the task's result, in ms.
+    pub result_getting_time: u64,
     pub memory_bytes_spilled: u64,
     pub disk_bytes_spilled: u64,
     pub peak_execution_memory: u64,
@@ -109,19 +434,19 @@ pub struct TaskMetrics {
     pub shuffle_write_metrics: Option<ShuffleWriteMetrics>,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct InputMetrics {
     pub bytes_read: u64,
     pub records_read: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct OutputMetrics {
     pub bytes_written: u64,
     pub records_written: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ShuffleReadMetrics {
     pub remote_blocks_fetched: u64,
     pub local_blocks_fetched: u64,
@@ -129,9 +454,28 @@ pub struct ShuffleReadMetrics {
     pub remote_bytes_read: u64,
     pub local_bytes_read: u64,
     pub records_read: u64,
+    /// Whether the task read through push-based shuffle (Spark 3.2+).
+    pub push_based_shuffle: bool,
+    pub remote_merged_blocks_fetched: u64,
+    pub local_merged_blocks_fetched: u64,
+    pub remote_merged_bytes_read: u64,
+    pub local_merged_bytes_read: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+impl ShuffleReadMetrics {
+    /// Percentage of the shuffle bytes read from merged blocks. The merged
+    /// bytes are also counted in the local and remote totals.
+    pub fn push_merge_ratio(&self) -> Option<f64> {
+        let total = self.local_bytes_read + self.remote_bytes_read;
+        if !self.push_based_shuffle || total == 0 {
+            return None;
+        }
+        let merged = self.local_merged_bytes_read + self.remote_merged_bytes_read;
+        Some((merged as f64 / total as f64 * 100.0).min(100.0))
+    }
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct ShuffleWriteMetrics {
     pub bytes_written: u64,
     pub write_time: u64,
@@ -160,6 +504,91 @@ pub struct Executor {
     pub max_off_heap_memory: u64,
     pub memory_used: u64,
     pub disk_used: u64,
+    pub memory_samples: Vec<u64>,
+    /// Only reported by Spark 3.1+.
+    pub resource_profile_id: Option<u32>,
+    /// Everything reported under `Executor Info.Attributes`.
+    pub attributes: HashMap<String, String>,
+    /// `kubernetes.*` executor attributes such as the pod and node name.
+    pub k8s_attributes: HashMap<String, String>,
+    /// GPUs and other custom resources from `Executor Info.Resources` (Spark 3.0+).
+    pub resources: HashMap<String, ResourceAmount>,
+}
+
+/// How much of a custom resource an executor was given, and which device
+/// addresses it got.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct ResourceAmount {
+    pub amount: u64,
+    pub addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ClusterNode {
+    pub host: String,
+    pub port: u16,
+    pub cores: u32,
+    pub is_active: bool,
+}
+
+/// A block manager and the storage memory it registered with, from
+/// `SparkListenerBlockManagerAdded`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockManager {
+    pub executor_id: String,
+    pub host: String,
+    pub port: u16,
+    /// On-heap plus off-heap storage memory.
+    pub max_memory: u64,
+    pub max_on_heap_memory: u64,
+    pub max_off_heap_memory: u64,
+}
+
+/// Blocks taken over from a decommissioned executor.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BlockManagerMigration {
+    pub source_executor_id: String,
+    pub target_executor_id: String,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub num_blocks: u64,
+}
+
+/// A stage attempt whose tasks could not be placed on any executor, typically
+/// because exclusion left none available. Spark waits, so the job hangs.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct UnschedulableEvent {
+    pub stage_id: u64,
+    pub stage_attempt_id: u64,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// A non-executor process announced to the listener bus, such as a YARN AM.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MiscProcess {
+    pub name: String,
+    pub host: String,
+    pub port: u16,
+    pub timestamp: Option<DateTime<Utc>>,
+}
+
+/// Resources requested per executor and per task by a stage-level resource
+/// profile. Amounts are keyed by resource name ("cores", "memory", "gpu", ...);
+/// executor memory amounts are in MiB.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ResourceProfile {
+    pub profile_id: u32,
+    pub executor_resources: HashMap<String, u64>,
+    pub task_resources: HashMap<String, f64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct Worker {
+    pub worker_id: String,
+    pub host: String,
+    pub port: u16,
+    pub cores: u32,
+    pub memory: u64,
+    pub is_active: bool,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -173,7 +602,15 @@ pub struct RddInfo {
     pub disk_size: u64,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize)]
+/// YARN scheduling context taken from the Spark properties.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct YarnInfo {
+    pub queue: Option<String>,
+    pub tags: Vec<String>,
+    pub max_app_attempts: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Environment {
     pub spark_properties: HashMap<String, String>,
     pub hadoop_properties: HashMap<String, String>,
@@ -184,15 +621,51 @@ pub struct Environment {
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SqlExecution {
     pub execution_id: u64,
+    /// UUID that stays the same across retries of the query, unlike
+    /// `execution_id` which restarts at 0 in every application.
+    pub run_id: Option<String>,
     pub description: String,
     pub details: String,
-    pub physical_plan_description: String,
+    /// The logical plan from `planDescription`.
+    pub plan_description: String,
+    /// The plan as first submitted, before adaptive execution rewrote it.
+    pub initial_physical_plan: String,
+    /// The latest plan: the final one once AQE has finished re-planning.
+    pub physical_plan: String,
     pub submission_time: DateTime<Utc>,
     pub completion_time: Option<DateTime<Utc>>,
     pub status: SqlExecutionStatus,
     pub jobs: Vec<u64>,
     pub stages: Vec<u64>,
+    /// Values of the metrics in `metric_definitions`, filled in once the
+    /// whole log is read.
     pub metrics: Vec<SqlMetric>,
+    /// The SQL metrics the plan declares, with the accumulator each one is
+    /// reported through.
+    pub metric_definitions: Vec<SqlMetricDefinition>,
+    /// Configs that differ from the session defaults (Spark 3.4+).
+    pub modified_configs: HashMap<String, String>,
+    /// Metric name and value pairs reported by AQE after re-planning.
+    pub aqe_metric_updates: Vec<(String, i64)>,
+    /// Plan metric names keyed by accumulator ID, from `sparkPlanInfo`.
+    pub metric_names: HashMap<u64, String>,
+    /// RDDs the plan's leaf nodes read, from their `inputRDDs`.
+    pub input_rdd_ids: Vec<u64>,
+    pub error_message: Option<String>,
+    /// Shuffle partitions left after AQE coalescing, summed over the
+    /// shuffle reader nodes of the latest plan.
+    pub aqe_coalesced_partitions: Option<u64>,
+    /// The shuffle partition count AQE coalesced from, resolved from the
+    /// session configs once the log is read.
+    pub aqe_initial_partitions: Option<u64>,
+    /// Whether AQE split skewed partitions for a skew join in any plan update.
+    pub has_skew_join: bool,
+    /// Broadcast hash joins in the latest AQE plan beyond those planned
+    /// up front, i.e. sort-merge joins AQE converted at runtime.
+    pub aqe_broadcast_joins_added: u32,
+    /// Operators listed in the numbered `(N) Name` entries of the formatted
+    /// physical plan, in plan order.
+    pub plan_operators: Vec<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -202,9 +675,265 @@ pub enum SqlExecutionStatus {
     Failed,
 }
 
+impl fmt::Display for SqlExecutionStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            SqlExecutionStatus::Running => "RUNNING",
+            SqlExecutionStatus::Completed => "COMPLETED",
+            SqlExecutionStatus::Failed => "FAILED",
+        };
+        f.write_str(text)
+    }
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct SqlMetric {
     pub name: String,
     pub value: u64,
     pub metric_type: String,
-}
\ No newline at end of file
+}
+
+/// A SQL metric as declared in the plan. `metric_type` is Spark's, e.g.
+/// "sum", "size", "timing" or "nsTiming".
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+pub struct SqlMetricDefinition {
+    pub accumulator_id: u64,
+    pub name: String,
+    pub metric_type: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ParseWarning {
+    pub line_number: usize,
+    pub content: String,
+    pub error: String,
+}
+
+/// Assembles a `SparkEventLog` in memory, filling every field the caller
+/// does not care about, so tests can set up just the jobs, stages, tasks and
+/// executors they exercise.
+pub struct SparkEventLogBuilder {
+    start_time: DateTime<Utc>,
+    event_log: SparkEventLog,
+}
+
+impl SparkEventLogBuilder {
+    pub fn new() -> Self {
+        let start_time = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap_or_default();
+        let application_info = ApplicationInfo {
+            app_id: "app-test".to_string(),
+            app_name: "test".to_string(),
+            app_attempt_id: None,
+            start_time,
+            end_time: None,
+            exit_code: None,
+            user: "spark".to_string(),
+            spark_version: "3.5.0".to_string(),
+        };
+        Self {
+            start_time,
+            event_log: SparkEventLog {
+                application_attempts: vec![application_info.clone()],
+                application_info,
+                jobs: HashMap::new(),
+                stages: HashMap::new(),
+                tasks: HashMap::new(),
+                executors: HashMap::new(),
+                cluster_nodes: HashMap::new(),
+                workers: HashMap::new(),
+                block_manager_migrations: Vec::new(),
+                driver_block_manager: None,
+                excluded_nodes: Vec::new(),
+                unschedulable_events: Vec::new(),
+                misc_processes: Vec::new(),
+                resource_profiles: HashMap::new(),
+                environment: Environment::default(),
+                sql_executions: HashMap::new(),
+                parse_warnings: Vec::new(),
+                ordering_warnings: Vec::new(),
+                previous_attempts: Vec::new(),
+            },
+        }
+    }
+
+    /// A succeeded job running `stage_ids`.
+    pub fn add_job(mut self, job_id: u64, stage_ids: &[u64]) -> Self {
+        self.event_log.jobs.insert(job_id, Job {
+            job_id,
+            name: format!("job {}", job_id),
+            description: None,
+            submission_time: self.start_time,
+            completion_time: Some(self.start_time + chrono::Duration::seconds(1)),
+            stage_ids: stage_ids.to_vec(),
+            status: JobStatus::Succeeded,
+            num_tasks: 0,
+            num_active_tasks: 0,
+            num_completed_tasks: 0,
+            num_skipped_tasks: 0,
+            num_failed_tasks: 0,
+            stage_task_counts: HashMap::new(),
+            properties: HashMap::new(),
+            sql_execution_id: None,
+            failure_reason: None,
+        });
+        self
+    }
+
+    /// The first attempt of a completed stage.
+    pub fn add_stage(mut self, stage_id: u64, num_tasks: u64) -> Self {
+        self.event_log.stages.insert((stage_id, 0), Stage {
+            stage_id,
+            stage_attempt_id: 0,
+            name: format!("stage {}", stage_id),
+            num_tasks,
+            parent_ids: Vec::new(),
+            rdd_info: Vec::new(),
+            submission_time: Some(self.start_time),
+            completion_time: Some(self.start_time + chrono::Duration::seconds(1)),
+            status: StageStatus::Complete,
+            task_metrics: None,
+            properties: HashMap::new(),
+            sql_execution_id: None,
+            details: String::new(),
+            executor_peak_metrics: HashMap::new(),
+            failure_reason: None,
+            task_binary_size: None,
+        });
+        self
+    }
+
+    /// A successful task in the first attempt of `stage_id`. The host is taken
+    /// from the executor when it was added first.
+    pub fn add_task(mut self, task_id: u64, stage_id: u64, executor_id: &str) -> Self {
+        let task_index = self.event_log.tasks_for_stage(stage_id).len() as u64;
+        let host = self.event_log.executors.get(executor_id)
+            .map(|e| e.host.clone())
+            .unwrap_or_else(|| "localhost".to_string());
+        self.event_log.tasks.insert(task_id, Task {
+            task_id,
+            stage_id,
+            stage_attempt_id: 0,
+            partition_id: task_index,
+            task_index,
+            executor_id: executor_id.to_string(),
+            host,
+            launch_time: self.start_time,
+            finish_time: Some(self.start_time + chrono::Duration::milliseconds(100)),
+            status: TaskStatus::Success,
+            failure_reason: None,
+            metrics: None,
+        });
+        self
+    }
+
+    /// An active four-core executor.
+    pub fn add_executor(mut self, executor_id: &str, host: &str) -> Self {
+        self.event_log.executors.insert(executor_id.to_string(), Executor {
+            executor_id: executor_id.to_string(),
+            host: host.to_string(),
+            port: 0,
+            is_active: true,
+            total_cores: 4,
+            max_tasks: 4,
+            active_tasks: 0,
+            failed_tasks: 0,
+            completed_tasks: 0,
+            total_tasks: 0,
+            total_duration: 0,
+            total_gc_time: 0,
+            total_input_bytes: 0,
+            total_shuffle_read: 0,
+            total_shuffle_write: 0,
+            max_memory: 0,
+            max_on_heap_memory: 0,
+            max_off_heap_memory: 0,
+            memory_used: 0,
+            disk_used: 0,
+            memory_samples: Vec::new(),
+            resource_profile_id: None,
+            attributes: HashMap::new(),
+            k8s_attributes: HashMap::new(),
+            resources: HashMap::new(),
+        });
+        self
+    }
+
+    /// A completed SQL execution that ran `job_ids`; jobs already added are
+    /// linked back to it.
+    pub fn add_sql_execution(mut self, execution_id: u64, description: &str, job_ids: &[u64]) -> Self {
+        for job_id in job_ids {
+            if let Some(job) = self.event_log.jobs.get_mut(job_id) {
+                job.sql_execution_id = Some(execution_id);
+            }
+        }
+        self.event_log.sql_executions.insert(execution_id, SqlExecution {
+            execution_id,
+            run_id: None,
+            description: description.to_string(),
+            details: String::new(),
+            plan_description: String::new(),
+            initial_physical_plan: String::new(),
+            physical_plan: String::new(),
+            submission_time: self.start_time,
+            completion_time: Some(self.start_time + chrono::Duration::milliseconds(1500)),
+            status: SqlExecutionStatus::Completed,
+            jobs: job_ids.to_vec(),
+            stages: Vec::new(),
+            metrics: Vec::new(),
+            metric_definitions: Vec::new(),
+            modified_configs: HashMap::new(),
+            aqe_metric_updates: Vec::new(),
+            metric_names: HashMap::new(),
+            input_rdd_ids: Vec::new(),
+            error_message: None,
+            aqe_coalesced_partitions: None,
+            aqe_initial_partitions: None,
+            has_skew_join: false,
+            aqe_broadcast_joins_added: 0,
+            plan_operators: Vec::new(),
+        });
+        self
+    }
+
+    pub fn build(self) -> SparkEventLog {
+        self.event_log
+    }
+}
+
+impl Default for SparkEventLogBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Facts about the event log file(s) themselves rather than their contents.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ParseMetadata {
+    pub file_size_bytes: u64,
+    pub line_count: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_totals_executor_time_across_tasks() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_executor("1", "worker-a")
+            .add_stage(0, 2)
+            .add_task(0, 0, "1")
+            .add_task(1, 0, "1")
+            .build();
+        // 1.6ms and 1.6ms of CPU: 3ms in total, not 1ms + 1ms
+        for task in event_log.tasks.values_mut() {
+            task.metrics = Some(TaskMetrics { execution_time: 40, cpu_time: 1_600_000, gc_time: 10, ..TaskMetrics::default() });
+        }
+
+        assert_eq!(event_log.total_executor_wall_time_ms(), 80);
+        assert_eq!(event_log.total_executor_cpu_time_ms(), 3);
+        let summary = event_log.summary();
+        assert_eq!((summary.total_executor_time_ms, summary.total_executor_cpu_time_ms), (80, 3));
+        assert_eq!(summary.gc_overhead_pct, 25.0);
+    }
+}
@@ -1,68 +1,327 @@
 use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use serde_json::Value;
+use std::cell::Cell;
 use std::collections::HashMap;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
-use std::path::Path;
+use std::io::{BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 
 use crate::models::*;
 
-pub fn parse_event_log(log_path: &Path) -> Result<SparkEventLog> {
+pub mod validation;
+
+/// How many lines `parse_event_log_with_progress` reads between callbacks.
+const PROGRESS_INTERVAL_LINES: u64 = 10_000;
+
+/// Which files make up a log, and post-processing applied to every parsed log.
+#[derive(Debug, Clone, Default)]
+pub struct ParseOptions {
+    /// Read the numbered siblings of the log file too (`app.log`, `app.log.1`, ...).
+    pub rolling: bool,
+    /// Only keep jobs, stages and tasks that started at or after this time.
+    pub since: Option<DateTime<Utc>>,
+}
+
+pub fn parse_event_log(log_path: &Path) -> Result<(SparkEventLog, ParseMetadata)> {
+    parse_event_log_with_options(log_path, &ParseOptions::default())
+}
+
+pub fn parse_event_log_with_options(log_path: &Path, options: &ParseOptions) -> Result<(SparkEventLog, ParseMetadata)> {
+    parse_event_log_with_progress(log_path, options, |_, _| {})
+}
+
+/// Like `parse_event_log_with_options`, but calls `progress(bytes_read, total_bytes)`
+/// every 10,000 lines and once more at the end. Bytes are counted on disk,
+/// so compressed logs report progress through the compressed file.
+pub fn parse_event_log_with_progress(log_path: &Path, options: &ParseOptions, progress: impl FnMut(u64, u64)) -> Result<(SparkEventLog, ParseMetadata)> {
+    parse_event_log_files(&log_parts(log_path, options)?, options, progress)
+}
+
+/// Total size on disk of the files `log_path` is read from.
+pub fn event_log_size(log_path: &Path, options: &ParseOptions) -> Result<u64> {
+    log_parts(log_path, options)?.iter()
+        .map(|part| {
+            std::fs::metadata(&part.path)
+                .map(|m| m.len())
+                .with_context(|| format!("Failed to read event log metadata: {}", part.path.display()))
+        })
+        .sum()
+}
+
+/// One file of a possibly rolled event log.
+struct LogPart {
+    path: PathBuf,
+    /// Which parts are missing just before this one, if any.
+    missing_before: Option<String>,
+}
+
+fn log_parts(log_path: &Path, options: &ParseOptions) -> Result<Vec<LogPart>> {
+    if options.rolling {
+        return find_rolling_log_files(log_path);
+    }
+    Ok(vec![LogPart { path: log_path.to_path_buf(), missing_before: None }])
+}
+
+/// Find `log_path` and its rolled siblings (`app.log`, `app.log.1`,
+/// `app.log.2`, ...), to be read in order as one logical event log.
+fn find_rolling_log_files(log_path: &Path) -> Result<Vec<LogPart>> {
+    let file_name = log_path.file_name()
+        .and_then(|n| n.to_str())
+        .with_context(|| format!("Invalid event log path: {}", log_path.display()))?;
+    
+    // Accept either the base file or any numbered part as the starting point
+    let base_name = match file_name.rsplit_once('.') {
+        Some((base, suffix)) if suffix.parse::<u64>().is_ok() => base,
+        _ => file_name,
+    };
+    
+    let dir = match log_path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        _ => Path::new("."),
+    };
+    
+    let mut parts: Vec<(u64, PathBuf)> = Vec::new();
+    for entry in std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read log directory: {}", dir.display()))?
+    {
+        let path = entry?.path();
+        let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+            continue;
+        };
+        
+        if name == base_name {
+            parts.push((0, path));
+        } else if let Some(index) = name.strip_prefix(base_name)
+            .and_then(|rest| rest.strip_prefix('.'))
+            .and_then(|suffix| suffix.parse::<u64>().ok())
+        {
+            parts.push((index, path));
+        }
+    }
+    
+    if parts.is_empty() {
+        anyhow::bail!("No rolling event log files found for {}", log_path.display());
+    }
+    
+    parts.sort_by_key(|(index, _)| *index);
+    
+    let mut expected = if parts[0].0 == 0 { 0 } else { 1 };
+    let mut log_parts = Vec::new();
+    for (index, path) in parts {
+        let missing_before = (index != expected).then(|| {
+            let missing = if index - expected == 1 {
+                format!("part {}", expected)
+            } else {
+                format!("parts {}-{}", expected, index - 1)
+            };
+            format!("Rolling event log {} is missing {}, continuing", base_name, missing)
+        });
+        log_parts.push(LogPart { path, missing_before });
+        expected = index + 1;
+    }
+    
+    Ok(log_parts)
+}
+
+/// Stream identifier that starts every Snappy framed stream.
+const SNAPPY_MAGIC: &[u8] = b"\xff\x06\x00\x00sNaPpY";
+
+/// Counts the bytes read from the underlying file, before any decompression.
+struct CountingReader<R> {
+    inner: R,
+    bytes_read: Rc<Cell<u64>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.bytes_read.set(self.bytes_read.get() + n as u64);
+        Ok(n)
+    }
+}
+
+/// Open an event log, transparently decompressing it when needed. New
+/// compression formats only need to be detected here.
+fn open_event_log_reader(log_path: &Path, bytes_read: Rc<Cell<u64>>) -> Result<Box<dyn BufRead>> {
     let file = File::open(log_path)
         .with_context(|| format!("Failed to open event log: {}", log_path.display()))?;
+    let mut reader = BufReader::new(CountingReader { inner: file, bytes_read });
     
-    let reader = BufReader::new(file);
-    
-    let mut application_info = None;
-    let mut jobs = HashMap::new();
-    let mut stages = HashMap::new();
-    let mut tasks = HashMap::new();
-    let mut executors = HashMap::new();
-    let mut environment = Environment {
-        spark_properties: HashMap::new(),
-        hadoop_properties: HashMap::new(),
-        system_properties: HashMap::new(),
-        classpath_entries: HashMap::new(),
-    };
-    let mut sql_executions = HashMap::new();
+    let has_snappy_extension = log_path.extension().is_some_and(|ext| ext == "snappy");
+    let has_snappy_magic = reader.fill_buf()
+        .with_context(|| format!("Failed to read event log: {}", log_path.display()))?
+        .starts_with(SNAPPY_MAGIC);
+    
+    if has_snappy_extension || has_snappy_magic {
+        return Ok(Box::new(BufReader::new(snap::read::FrameDecoder::new(reader))));
+    }
+    
+    Ok(Box::new(reader))
+}
+
+fn parse_event_log_files(log_parts: &[LogPart], options: &ParseOptions, mut progress: impl FnMut(u64, u64)) -> Result<(SparkEventLog, ParseMetadata)> {
+    let mut metadata = ParseMetadata::default();
+    let bytes_read = Rc::new(Cell::new(0));
+    let mut readers = Vec::new();
+    for LogPart { path, .. } in log_parts {
+        metadata.file_size_bytes += std::fs::metadata(path)
+            .with_context(|| format!("Failed to read event log metadata: {}", path.display()))?
+            .len();
+        readers.push(open_event_log_reader(path, bytes_read.clone())?);
+    }
+    
+    // Concatenate the line streams so rolled parts behave like one file,
+    // remembering which part each line came from to report gaps
+    let lines = readers.into_iter()
+        .enumerate()
+        .flat_map(|(part, reader)| reader.split(b'\n').map(move |line| (part, line)));
     
-    for line in reader.lines() {
+    let mut finished_attempts = Vec::new();
+    let mut attempt = AttemptState::default();
+    let mut previous_event_time = None;
+    let mut gaps = log_parts.iter().enumerate()
+        .filter_map(|(part, log_part)| Some((part, log_part.path.as_path(), log_part.missing_before.as_ref()?)))
+        .peekable();
+    
+    for (index, (part, line)) in lines.enumerate() {
+        let line_number = index + 1;
+        metadata.line_count += 1;
+        // A gap is reported on the first line read after it
+        while let Some((_, path, missing)) = gaps.next_if(|(gap_part, _, _)| *gap_part <= part) {
+            attempt.parse_warnings.push(ParseWarning {
+                line_number,
+                content: path.display().to_string().chars().take(80).collect(),
+                error: missing.clone(),
+            });
+        }
+        if metadata.line_count.is_multiple_of(PROGRESS_INTERVAL_LINES) {
+            progress(bytes_read.get(), metadata.file_size_bytes);
+        }
         let line = line.context("Failed to read line from event log")?;
-        let event: Value = serde_json::from_str(&line)
-            .context("Failed to parse JSON from event log line")?;
+        // A stray invalid byte should cost at most that one value, not the whole log
+        let line = String::from_utf8_lossy(&line);
+        let line = line.trim_end_matches('\r').trim_start_matches('\u{feff}');
+        if line.trim().is_empty() {
+            continue;
+        }
+        
+        let event: Value = match serde_json::from_str(line) {
+            Ok(event) => event,
+            Err(e) => {
+                attempt.parse_warnings.push(ParseWarning {
+                    line_number,
+                    content: line.chars().take(80).collect(),
+                    error: format!("Malformed JSON: {}", e),
+                });
+                continue;
+            }
+        };
+        
+        let Some(event_type) = event.get("Event").and_then(|v| v.as_str()) else {
+            continue;
+        };
+        
+        // Each application master restart emits its own start event, after
+        // which IDs start over, so every attempt gets its own event log. The
+        // log start, environment, resource profiles and driver block manager
+        // come before the application start, so a log start event after the
+        // current attempt has started opens the next one
+        let is_new_attempt = match event_type {
+            "SparkListenerLogStart" => attempt.application_info.is_some(),
+            "SparkListenerApplicationStart" => {
+                let attempt_id = event.get("App Attempt ID").and_then(|v| v.as_str());
+                attempt.application_info.as_ref()
+                    .is_some_and(|info| info.app_attempt_id.as_deref() != attempt_id)
+            }
+            _ => false,
+        };
+        if is_new_attempt {
+            finished_attempts.push(std::mem::take(&mut attempt));
+        }
         
-        if let Some(event_type) = event.get("Event").and_then(|v| v.as_str()) {
+        let AttemptState {
+            application_info,
+            jobs,
+            stages,
+            tasks,
+            executors,
+            cluster_nodes,
+            workers,
+            block_manager_migrations,
+            driver_block_manager,
+            excluded_nodes,
+            unschedulable_events,
+            misc_processes,
+            resource_profiles,
+            environment,
+            sql_executions,
+            getting_result_starts,
+            executor_task_time,
+            accumulator_values,
+            initial_broadcast_joins,
+            aqe_metric_definitions,
+            sql_starts,
+            parse_warnings,
+        } = &mut attempt;
+        
+        // A single bad event should not abort the whole log
+        let handled = (|| -> Result<()> {
             match event_type {
                 "SparkListenerApplicationStart" => {
-                    application_info = Some(parse_application_start(&event)?);
+                    *application_info = Some(parse_application_start(&event)?);
                 }
                 "SparkListenerApplicationEnd" => {
-                    if let Some(ref mut app_info) = application_info {
+                    if let Some(app_info) = application_info.as_mut() {
                         app_info.end_time = parse_timestamp(&event, "Timestamp");
+                        app_info.exit_code = event.get("Exit Code").and_then(|v| v.as_i64()).map(|code| code as i32);
                     }
                 }
                 "SparkListenerJobStart" => {
                     let job = parse_job_start(&event)?;
                     jobs.insert(job.job_id, job);
+                    // Stage attempts that already failed (e.g. before a retry) carry their
+                    // failure here, and may never get a StageCompleted of their own
+                    for stage in parse_failed_stage_infos(&event) {
+                        let key = (stage.stage_id, stage.stage_attempt_id);
+                        match stages.get_mut(&key) {
+                            Some(existing) => {
+                                existing.status = StageStatus::Failed;
+                                existing.failure_reason = stage.failure_reason;
+                            }
+                            None => {
+                                stages.insert(key, stage);
+                            }
+                        }
+                    }
                 }
                 "SparkListenerJobEnd" => {
                     if let Some(job_id) = event.get("Job ID").and_then(|v| v.as_u64()) {
                         if let Some(job) = jobs.get_mut(&job_id) {
                             job.completion_time = parse_timestamp(&event, "Completion Time");
                             job.status = parse_job_result(&event);
+                            job.failure_reason = parse_job_failure_reason(&event);
                         }
                     }
                 }
                 "SparkListenerStageSubmitted" => {
                     let stage = parse_stage_submitted(&event)?;
-                    stages.insert(stage.stage_id, stage);
+                    update_job_task_counts(jobs, stages, &stage);
+                    stages.insert((stage.stage_id, stage.stage_attempt_id), stage);
                 }
                 "SparkListenerStageCompleted" => {
                     if let Some(stage_info) = event.get("Stage Info") {
                         if let Some(stage_id) = stage_info.get("Stage ID").and_then(|v| v.as_u64()) {
-                            if let Some(stage) = stages.get_mut(&stage_id) {
+                            let attempt_id = stage_info.get("Stage Attempt ID").and_then(|v| v.as_u64()).unwrap_or(0);
+                            if let Some(stage) = stages.get_mut(&(stage_id, attempt_id)) {
                                 stage.completion_time = parse_timestamp(stage_info, "Completion Time");
+                                if let Some(size) = stage_info.get("Task Binary File Size").and_then(|v| v.as_u64()) {
+                                    stage.task_binary_size = Some(size);
+                                }
+                                stage.failure_reason = stage_info.get("Failure Reason")
+                                    .and_then(|v| v.as_str())
+                                    .map(|reason| reason.to_string());
                                 stage.status = if stage_info.get("Failure Reason").is_some() {
                                     StageStatus::Failed
                                 } else {
@@ -70,19 +329,69 @@ pub fn parse_event_log(log_path: &Path) -> Result<SparkEventLog> {
                                 };
                             }
                         }
+                        // A metric can be updated by several stages; each reports the running total
+                        for (id, value) in parse_stage_accumulables(stage_info) {
+                            let total = accumulator_values.entry(id).or_default();
+                            *total = (*total).max(value);
+                        }
+                    }
+                }
+                "SparkListenerDriverAccumUpdates"
+                | "org.apache.spark.sql.execution.ui.SparkListenerDriverAccumUpdates" => {
+                    // Metrics the driver updates itself, e.g. broadcast build time
+                    let updates = event.get("accumUpdates").and_then(|v| v.as_array()).into_iter().flatten();
+                    for update in updates.filter_map(|u| u.as_array()) {
+                        if let [id, value] = update.as_slice() {
+                            if let (Some(id), Some(value)) = (id.as_u64(), value.as_u64()) {
+                                *accumulator_values.entry(id).or_default() += value;
+                            }
+                        }
+                    }
+                }
+                "SparkListenerStageExecutorMetrics" => {
+                    let metrics = parse_stage_executor_metrics(&event)?;
+                    let attempt_id = event.get("Stage Attempt ID").and_then(|v| v.as_u64()).unwrap_or(0);
+                    if let Some(stage) = stages.get_mut(&(metrics.stage_id, attempt_id)) {
+                        stage.executor_peak_metrics.insert(metrics.executor_id.clone(), metrics);
                     }
                 }
                 "SparkListenerTaskStart" => {
                     let task = parse_task_start(&event)?;
                     tasks.insert(task.task_id, task);
                 }
+                "SparkListenerTaskGettingResult" => {
+                    if let Some(task_info) = event.get("Task Info") {
+                        let task_id = task_info.get("Task ID").and_then(|v| v.as_u64());
+                        let started = task_info.get("Getting Result Time").and_then(|v| v.as_u64());
+                        if let (Some(task_id), Some(started)) = (task_id, started) {
+                            getting_result_starts.insert(task_id, started);
+                        }
+                    }
+                }
                 "SparkListenerTaskEnd" => {
                     if let Some(task_info) = event.get("Task Info") {
                         if let Some(task_id) = task_info.get("Task ID").and_then(|v| v.as_u64()) {
                             if let Some(task) = tasks.get_mut(&task_id) {
                                 task.finish_time = parse_timestamp(task_info, "Finish Time");
                                 task.status = parse_task_status(task_info);
+                                task.failure_reason = parse_task_failure_reason(&event);
                                 task.metrics = parse_task_metrics(&event);
+                                
+                                // Older logs leave Getting Result Time at 0 on the end event
+                                let finish_time = task_info.get("Finish Time").and_then(|v| v.as_u64());
+                                let started = getting_result_starts.remove(&task_id);
+                                if let (Some(metrics), Some(finish_time), Some(started)) = (task.metrics.as_mut(), finish_time, started) {
+                                    if metrics.result_getting_time == 0 && finish_time >= started {
+                                        metrics.result_getting_time = finish_time - started;
+                                        metrics.scheduler_delay = metrics.scheduler_delay.saturating_sub(metrics.result_getting_time);
+                                    }
+                                }
+                                
+                                if let Some(metrics) = &task.metrics {
+                                    let totals = executor_task_time.entry(task.executor_id.clone()).or_default();
+                                    totals.0 += metrics.execution_time;
+                                    totals.1 += metrics.gc_time;
+                                }
                             }
                         }
                     }
@@ -98,18 +407,153 @@ pub fn parse_event_log(log_path: &Path) -> Result<SparkEventLog> {
                         }
                     }
                 }
+                "SparkListenerNodeAdded" => {
+                    let node = parse_node_added(&event)?;
+                    cluster_nodes.insert(node.host.clone(), node);
+                }
+                "SparkListenerNodeRemoved" => {
+                    if let Some(host) = event.get("Host").and_then(|v| v.as_str()) {
+                        if let Some(node) = cluster_nodes.get_mut(host) {
+                            node.is_active = false;
+                        }
+                    }
+                }
+                "SparkListenerWorkerAdded" => {
+                    let worker = parse_worker_added(&event)?;
+                    workers.insert(worker.worker_id.clone(), worker);
+                }
+                "SparkListenerWorkerRemoved" => {
+                    if let Some(worker_id) = event.get("Worker Id").and_then(|v| v.as_str()) {
+                        if let Some(worker) = workers.get_mut(worker_id) {
+                            worker.is_active = false;
+                        }
+                    }
+                }
+                "SparkListenerBlockManagerAdded" => {
+                    if let Some(block_manager) = parse_block_manager_added(&event) {
+                        if block_manager.executor_id == "driver" {
+                            *driver_block_manager = Some(block_manager);
+                        } else if let Some(executor) = executors.get_mut(&block_manager.executor_id) {
+                            executor.max_on_heap_memory = block_manager.max_on_heap_memory;
+                            executor.max_off_heap_memory = block_manager.max_off_heap_memory;
+                        }
+                    }
+                }
+                "SparkListenerBlockManagerGainedOwnership" => {
+                    block_manager_migrations.push(parse_block_manager_migration(&event)?);
+                }
+                "SparkListenerUnschedulableTaskSetAdded"
+                | "org.apache.spark.scheduler.SparkListenerUnschedulableTaskSetAdded" => {
+                    unschedulable_events.push(parse_unschedulable_task_set(&event)?);
+                }
+                "SparkListenerNodeExcluded" | "SparkListenerNodeBlacklisted" => {
+                    let host = event.get("hostId")
+                        .and_then(|v| v.as_str())
+                        .context("Missing hostId")?;
+                    let time = parse_timestamp(&event, "time").context("Missing time")?;
+                    excluded_nodes.push((host.to_string(), time));
+                }
+                "SparkListenerMiscellaneousProcess" | "SparkListenerMiscellaneousProcessAdded" => {
+                    misc_processes.push(parse_misc_process(&event)?);
+                }
+                "SparkListenerResourceProfileAdded" => {
+                    let profile = parse_resource_profile(&event)?;
+                    resource_profiles.insert(profile.profile_id, profile);
+                }
+                "SparkListenerExecutorMetricsUpdate" => {
+                    if let Some(executor_id) = event.get("Executor ID").and_then(|v| v.as_str()) {
+                        if let Some(executor) = executors.get_mut(executor_id) {
+                            let samples = parse_heap_samples(&event);
+                            if let Some(latest) = samples.last() {
+                                executor.memory_used = *latest;
+                            }
+                            executor.memory_samples.extend(samples);
+                        }
+                    }
+                }
                 "SparkListenerEnvironmentUpdate" => {
-                    environment = parse_environment_update(&event)?;
+                    *environment = parse_environment_update(&event)?;
                 }
-                "SparkListenerSQLExecutionStart" => {
-                    let sql_execution = parse_sql_execution_start(&event)?;
+                "SparkListenerSQLExecutionStart"
+                | "org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionStart" => {
+                    let mut sql_execution = parse_sql_execution_start(&event)?;
+                    sql_starts.push(validation::ParsedEvent {
+                        event_type: event_type.to_string(),
+                        sql_execution_id: Some(sql_execution.execution_id),
+                    });
+                    if let Some(previous_time) = previous_event_time.filter(|time| sql_execution.submission_time < *time) {
+                        // Keep the timeline monotonic so durations stay sane
+                        parse_warnings.push(ParseWarning {
+                            line_number,
+                            content: line.chars().take(80).collect(),
+                            error: format!("SQL execution {} start time is before the previous event; clamped", sql_execution.execution_id),
+                        });
+                        sql_execution.submission_time = previous_time;
+                    }
+                    let broadcast_joins = event.get("sparkPlanInfo").map(count_broadcast_joins).unwrap_or(0);
+                    initial_broadcast_joins.insert(sql_execution.execution_id, broadcast_joins);
                     sql_executions.insert(sql_execution.execution_id, sql_execution);
                 }
-                "SparkListenerSQLExecutionEnd" => {
+                "SparkListenerSQLAdaptiveExecutionUpdate"
+                | "org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveExecutionUpdate" => {
+                    // AQE re-planned the query; the new plan may add stages and metrics
+                    if let Some(execution_id) = event.get("executionId").and_then(|v| v.as_u64()) {
+                        if let Some(sql_execution) = sql_executions.get_mut(&execution_id) {
+                            if let Some(plan) = event.get("physicalPlanDescription").and_then(|v| v.as_str()) {
+                                if sql_execution.initial_physical_plan.is_empty() {
+                                    sql_execution.initial_physical_plan = plan.to_string();
+                                }
+                                sql_execution.physical_plan = plan.to_string();
+                            }
+                            if let Some(plan_info) = event.get("sparkPlanInfo") {
+                                let mut definitions = Vec::new();
+                                collect_plan_metric_definitions(plan_info, &mut definitions);
+                                record_metric_definitions(sql_execution, definitions);
+                                sql_execution.aqe_coalesced_partitions = coalesced_partition_count(plan_info);
+                                sql_execution.has_skew_join |= plan_has_skew_join(plan_info);
+                                let initial = initial_broadcast_joins.get(&execution_id).copied().unwrap_or(0);
+                                sql_execution.aqe_broadcast_joins_added = count_broadcast_joins(plan_info).saturating_sub(initial);
+                                collect_plan_input_rdds(plan_info, &mut sql_execution.input_rdd_ids);
+                            }
+                        }
+                    }
+                }
+                "SparkListenerSQLAdaptiveSQLMetricUpdates"
+                | "org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveSQLMetricUpdates" => {
                     if let Some(execution_id) = event.get("executionId").and_then(|v| v.as_u64()) {
                         if let Some(sql_execution) = sql_executions.get_mut(&execution_id) {
-                            sql_execution.completion_time = parse_timestamp(&event, "time");
-                            sql_execution.status = crate::models::SqlExecutionStatus::Completed;
+                            let updates = parse_aqe_metric_updates(&event, &sql_execution.metric_names);
+                            sql_execution.aqe_metric_updates.extend(updates);
+                            // Spark itself only declares the metrics here; their values
+                            // arrive later through accumulator updates
+                            let definitions: Vec<_> = event.get("sqlPlanMetrics")
+                                .and_then(|v| v.as_array())
+                                .into_iter()
+                                .flatten()
+                                .filter(|metric| metric.get("value").is_none())
+                                .filter_map(parse_metric_definition)
+                                .collect();
+                            record_metric_definitions(sql_execution, definitions.clone());
+                            aqe_metric_definitions.entry(execution_id).or_default().extend(definitions);
+                        }
+                    }
+                }
+                "SparkListenerSQLExecutionEnd"
+                | "org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionEnd" => {
+                    if let Some(execution_id) = event.get("executionId").and_then(|v| v.as_u64()) {
+                        if let Some(sql_execution) = sql_executions.get_mut(&execution_id) {
+                            sql_execution.completion_time = parse_timestamp(&event, "time")
+                                .or_else(|| parse_timestamp(&event, "Time"));
+                            // Spark 3.4+ records why the query failed
+                            sql_execution.error_message = event.get("errorMessage")
+                                .and_then(|v| v.as_str())
+                                .filter(|message| !message.is_empty())
+                                .map(|message| message.to_string());
+                            sql_execution.status = if sql_execution.error_message.is_some() {
+                                crate::models::SqlExecutionStatus::Failed
+                            } else {
+                                crate::models::SqlExecutionStatus::Completed
+                            };
                         }
                     }
                 }
@@ -117,23 +561,423 @@ pub fn parse_event_log(log_path: &Path) -> Result<SparkEventLog> {
                     // Ignore other event types for now
                 }
             }
+            Ok(())
+        })();
+        
+        if let Err(e) = handled {
+            parse_warnings.push(ParseWarning {
+                line_number,
+                content: line.chars().take(80).collect(),
+                error: format!("Skipped {}: {:#}", event_type, e),
+            });
+        }
+        
+        if let Some(time) = event_time(&event) {
+            previous_event_time = Some(previous_event_time.map_or(time, |previous: DateTime<Utc>| previous.max(time)));
+        }
+    }
+    
+    // Gaps before parts that held no lines at all
+    for (_, path, missing) in gaps {
+        attempt.parse_warnings.push(ParseWarning {
+            line_number: metadata.line_count as usize,
+            content: path.display().to_string().chars().take(80).collect(),
+            error: missing.clone(),
+        });
+    }
+    
+    progress(bytes_read.get(), metadata.file_size_bytes);
+    
+    let mut attempts = finished_attempts.into_iter()
+        .chain(std::iter::once(attempt))
+        .map(|attempt| attempt.into_event_log())
+        .collect::<Result<Vec<_>>>()?;
+    
+    // Every attempt's log lists all attempts, so the header reads the same whichever is shown
+    let application_attempts: Vec<_> = attempts.iter().map(|log| log.application_info.clone()).collect();
+    for log in &mut attempts {
+        log.application_attempts = application_attempts.clone();
+    }
+    
+    let mut event_log = attempts.pop().context("No application start event found in event log")?;
+    event_log.previous_attempts = attempts;
+    
+    if let Some(since) = options.since {
+        filter_since(&mut event_log, since);
+    }
+    
+    Ok((event_log, metadata))
+}
+
+/// Everything read so far for one application attempt.
+#[derive(Default)]
+struct AttemptState {
+    application_info: Option<ApplicationInfo>,
+    jobs: HashMap<u64, Job>,
+    stages: HashMap<(u64, u64), Stage>,
+    tasks: HashMap<u64, Task>,
+    executors: HashMap<String, Executor>,
+    cluster_nodes: HashMap<String, ClusterNode>,
+    workers: HashMap<String, Worker>,
+    block_manager_migrations: Vec<BlockManagerMigration>,
+    driver_block_manager: Option<BlockManager>,
+    excluded_nodes: Vec<(String, DateTime<Utc>)>,
+    unschedulable_events: Vec<UnschedulableEvent>,
+    misc_processes: Vec<MiscProcess>,
+    resource_profiles: HashMap<u32, ResourceProfile>,
+    environment: Environment,
+    sql_executions: HashMap<u64, SqlExecution>,
+    // When the driver started fetching each task's result, in epoch ms
+    getting_result_starts: HashMap<u64, u64>,
+    // Run time and GC time of finished tasks, summed per executor ID
+    executor_task_time: HashMap<String, (u64, u64)>,
+    // Final value of each accumulator, for naming through SQL metric definitions
+    accumulator_values: HashMap<u64, u64>,
+    // Broadcast hash joins in each SQL execution's plan before AQE ran
+    initial_broadcast_joins: HashMap<u64, u32>,
+    // Metrics AQE declared without a value, per SQL execution, valued from
+    // accumulator_values once the whole log is read
+    aqe_metric_definitions: HashMap<u64, Vec<SqlMetricDefinition>>,
+    // SQL execution starts in log order, checked for out-of-order IDs once the attempt is read
+    sql_starts: Vec<validation::ParsedEvent>,
+    // Problems found on this attempt's lines, so each attempt shows only its own
+    parse_warnings: Vec<ParseWarning>,
+}
+
+impl AttemptState {
+    fn into_event_log(self) -> Result<SparkEventLog> {
+        let mut executors = self.executors;
+        for (executor_id, (run_time, gc_time)) in self.executor_task_time {
+            if let Some(executor) = executors.get_mut(&executor_id) {
+                executor.total_duration = run_time;
+                executor.total_gc_time = gc_time;
+            }
+        }
+        
+        let application_info = self.application_info
+            .context("No application start event found in event log")?;
+        
+        let mut event_log = SparkEventLog {
+            application_attempts: vec![application_info.clone()],
+            application_info,
+            jobs: self.jobs,
+            stages: self.stages,
+            tasks: self.tasks,
+            executors,
+            cluster_nodes: self.cluster_nodes,
+            workers: self.workers,
+            block_manager_migrations: self.block_manager_migrations,
+            driver_block_manager: self.driver_block_manager,
+            excluded_nodes: self.excluded_nodes,
+            unschedulable_events: self.unschedulable_events,
+            misc_processes: self.misc_processes,
+            resource_profiles: self.resource_profiles,
+            environment: self.environment,
+            sql_executions: self.sql_executions,
+            parse_warnings: self.parse_warnings,
+            ordering_warnings: validation::validate_event_ordering(&self.sql_starts),
+            previous_attempts: Vec::new(),
+        };
+        fixup_spark_version(&mut event_log);
+        link_sql_executions(&mut event_log);
+        fail_sql_executions_with_failed_jobs(&mut event_log);
+        fill_sql_metric_values(&mut event_log, &self.accumulator_values);
+        fill_aqe_metric_updates(&mut event_log, &self.accumulator_values, &self.aqe_metric_definitions);
+        close_running_sql_executions(&mut event_log);
+        resolve_aqe_initial_partitions(&mut event_log);
+        Ok(event_log)
+    }
+}
+
+/// Older builds (notably some EMR releases) leave "Spark Version" off the
+/// application start event; fall back to the `spark.version` property from
+/// the environment update, which may come later in the log.
+fn fixup_spark_version(log: &mut SparkEventLog) {
+    let Some(version) = log.environment.spark_properties.get("spark.version") else {
+        return;
+    };
+    
+    let attempts = log.application_attempts.iter_mut().chain(std::iter::once(&mut log.application_info));
+    for app_info in attempts.filter(|a| a.spark_version == "Unknown") {
+        app_info.spark_version = version.clone();
+    }
+}
+
+/// Before Spark 3.4 the execution end carries no error message, so a failed
+/// query looks completed. Like the Spark UI, treat an ended execution as
+/// failed when one of its jobs failed, taking that job's failure reason.
+fn fail_sql_executions_with_failed_jobs(log: &mut SparkEventLog) {
+    let ended = log.sql_executions.values_mut()
+        .filter(|e| matches!(e.status, crate::models::SqlExecutionStatus::Completed));
+    for execution in ended {
+        let mut failed_jobs = execution.jobs.iter()
+            .filter_map(|job_id| log.jobs.get(job_id))
+            .filter(|job| matches!(job.status, JobStatus::Failed));
+        if let Some(job) = failed_jobs.next() {
+            execution.status = crate::models::SqlExecutionStatus::Failed;
+            execution.error_message = Some(job.failure_reason.clone()
+                .unwrap_or_else(|| format!("Job {} failed", job.job_id)));
+        }
+    }
+}
+
+/// Executions without a `SparkListenerSQLExecutionEnd` stay `Running`,
+/// which is right for an in-progress or truncated log. Once the application
+/// itself has ended they can't still be running, so settle them from the
+/// application's outcome: a non-zero exit code means they failed.
+fn close_running_sql_executions(log: &mut SparkEventLog) {
+    let Some(end_time) = log.application_info.end_time else {
+        return;
+    };
+    let app_failed = log.application_info.exit_code.is_some_and(|code| code != 0);
+    
+    let running = log.sql_executions.values_mut()
+        .filter(|e| matches!(e.status, crate::models::SqlExecutionStatus::Running));
+    for execution in running {
+        execution.status = if app_failed {
+            crate::models::SqlExecutionStatus::Failed
+        } else {
+            crate::models::SqlExecutionStatus::Completed
+        };
+        execution.completion_time = Some(end_time);
+    }
+}
+
+/// The partition count AQE started from for each coalesced execution:
+/// `spark.sql.adaptive.coalescePartitions.initialPartitionNum` if set, else
+/// `spark.sql.shuffle.partitions`, looked up in the execution's modified
+/// configs and then the application's, defaulting to Spark's 200.
+fn resolve_aqe_initial_partitions(log: &mut SparkEventLog) {
+    const KEYS: [&str; 2] = ["spark.sql.adaptive.coalescePartitions.initialPartitionNum", "spark.sql.shuffle.partitions"];
+    let app_properties = &log.environment.spark_properties;
+    
+    for execution in log.sql_executions.values_mut().filter(|e| e.aqe_coalesced_partitions.is_some()) {
+        let configured = KEYS.iter().find_map(|key| {
+            execution.modified_configs.get(*key)
+                .or_else(|| app_properties.get(*key))
+                .and_then(|value| value.parse().ok())
+        });
+        execution.aqe_initial_partitions = Some(configured.unwrap_or(200));
+    }
+}
+
+/// Give each execution's declared metrics their accumulator values, in
+/// declaration order. Metrics that were never updated are left out.
+fn fill_sql_metric_values(log: &mut SparkEventLog, accumulator_values: &HashMap<u64, u64>) {
+    for execution in log.sql_executions.values_mut() {
+        execution.metrics = execution.metric_definitions.iter()
+            .filter_map(|definition| {
+                Some(SqlMetric {
+                    name: definition.name.clone(),
+                    value: *accumulator_values.get(&definition.accumulator_id)?,
+                    metric_type: definition.metric_type.clone(),
+                })
+            })
+            .collect();
+    }
+}
+
+/// Add the final values of the metrics AQE declared to each execution's
+/// `aqe_metric_updates`, skipping ones no accumulator update reported.
+fn fill_aqe_metric_updates(
+    log: &mut SparkEventLog,
+    accumulator_values: &HashMap<u64, u64>,
+    aqe_metric_definitions: &HashMap<u64, Vec<SqlMetricDefinition>>,
+) {
+    for (execution_id, definitions) in aqe_metric_definitions {
+        if let Some(execution) = log.sql_executions.get_mut(execution_id) {
+            let updates = definitions.iter().filter_map(|definition| {
+                let value = *accumulator_values.get(&definition.accumulator_id)?;
+                Some((definition.name.clone(), value as i64))
+            });
+            execution.aqe_metric_updates.extend(updates);
+        }
+    }
+}
+
+/// Fill `SqlExecution::jobs` and `SqlExecution::stages` from the
+/// `spark.sql.execution.id` each job and stage was submitted with. This is
+/// done once the whole log is read so stages that AQE added after
+/// re-planning are included too.
+///
+/// Older Spark versions don't set the property on stages, so a stage without
+/// one is attributed to the execution whose plan reads one of its RDDs (the
+/// earliest, if several do).
+fn link_sql_executions(log: &mut SparkEventLog) {
+    let mut execution_by_rdd = HashMap::new();
+    for execution in log.sql_executions.values() {
+        for &rdd_id in &execution.input_rdd_ids {
+            let id = execution_by_rdd.entry(rdd_id).or_insert(execution.execution_id);
+            *id = (*id).min(execution.execution_id);
         }
     }
+    for stage in log.stages.values_mut().filter(|s| s.sql_execution_id.is_none()) {
+        stage.sql_execution_id = stage.rdd_info.iter()
+            .find_map(|rdd| execution_by_rdd.get(&rdd.rdd_id).copied());
+    }
+    
+    for job in log.jobs.values() {
+        let Some(execution) = job.sql_execution_id.and_then(|id| log.sql_executions.get_mut(&id)) else {
+            continue;
+        };
+        if !execution.jobs.contains(&job.job_id) {
+            execution.jobs.push(job.job_id);
+        }
+    }
+    
+    for stage in log.stages.values() {
+        let Some(execution) = stage.sql_execution_id.and_then(|id| log.sql_executions.get_mut(&id)) else {
+            continue;
+        };
+        if !execution.stages.contains(&stage.stage_id) {
+            execution.stages.push(stage.stage_id);
+        }
+    }
+    
+    for execution in log.sql_executions.values_mut() {
+        execution.jobs.sort_unstable();
+        execution.stages.sort_unstable();
+    }
+}
+
+/// Drop jobs, stages, tasks and SQL executions that started before `since`.
+/// Stages that were never submitted are kept.
+fn filter_since(log: &mut SparkEventLog, since: DateTime<Utc>) {
+    log.jobs.retain(|_, job| job.submission_time >= since);
+    log.stages.retain(|_, stage| stage.submission_time.is_none_or(|t| t >= since));
+    log.tasks.retain(|_, task| task.launch_time >= since);
+    
+    // The per-executor totals were summed over every task while parsing
+    for executor in log.executors.values_mut() {
+        executor.total_duration = 0;
+        executor.total_gc_time = 0;
+    }
+    for task in log.tasks.values() {
+        if let (Some(executor), Some(metrics)) = (log.executors.get_mut(&task.executor_id), &task.metrics) {
+            executor.total_duration += metrics.execution_time;
+            executor.total_gc_time += metrics.gc_time;
+        }
+    }
+    
+    log.sql_executions.retain(|_, execution| execution.submission_time >= since);
+    for execution in log.sql_executions.values_mut() {
+        execution.jobs.retain(|job_id| log.jobs.contains_key(job_id));
+        execution.stages.retain(|stage_id| log.stages.keys().any(|(id, _)| id == stage_id));
+    }
+    
+    for attempt in &mut log.previous_attempts {
+        filter_since(attempt, since);
+    }
+}
+
+/// A file found while scanning a log directory, with what the picker shows
+/// for it or why it could not be read.
+pub struct LogFileEntry {
+    pub path: PathBuf,
+    pub result: std::result::Result<LogFileSummary, String>,
+}
+
+/// The few fields the log picker shows, read without building the full log.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogFileSummary {
+    pub app_name: String,
+    pub app_id: String,
+    pub job_count: usize,
+    /// `None` while the application is still running.
+    pub duration_ms: Option<u64>,
+}
+
+pub fn scan_log_dir(dir: &Path, recursive: bool, options: &ParseOptions) -> Result<Vec<LogFileEntry>> {
+    let mut paths = Vec::new();
+    collect_log_files(dir, recursive, &mut paths)?;
+    paths.sort();
+    
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let result = summarize_event_log(&path, options).map_err(|e| format!("{:#}", e));
+            LogFileEntry { path, result }
+        })
+        .collect())
+}
+
+/// Read only the application and job start events of `log_path`. Like the
+/// full parse, the summary describes the latest application attempt.
+fn summarize_event_log(log_path: &Path, options: &ParseOptions) -> Result<LogFileSummary> {
+    let reader = open_event_log_reader(log_path, Rc::new(Cell::new(0)))?;
+    let mut application_info: Option<ApplicationInfo> = None;
+    let mut job_count = 0;
     
-    let application_info = application_info
-        .context("No application start event found in event log")?;
+    for line in reader.split(b'\n') {
+        let line = line.context("Failed to read line from event log")?;
+        let line = String::from_utf8_lossy(&line);
+        // Skip the task and stage events without decoding them
+        let is_summarized = ["SparkListenerApplicationStart", "SparkListenerApplicationEnd", "SparkListenerJobStart"]
+            .iter()
+            .any(|event_type| line.contains(event_type));
+        if !is_summarized {
+            continue;
+        }
+        let Ok(event) = serde_json::from_str::<Value>(line.trim_start_matches('\u{feff}')) else {
+            continue;
+        };
+        
+        match event.get("Event").and_then(|v| v.as_str()) {
+            Some("SparkListenerApplicationStart") => {
+                application_info = Some(parse_application_start(&event)?);
+                job_count = 0;
+            }
+            Some("SparkListenerApplicationEnd") => {
+                if let Some(app_info) = application_info.as_mut() {
+                    app_info.end_time = parse_timestamp(&event, "Timestamp");
+                }
+            }
+            Some("SparkListenerJobStart") => {
+                // Jobs without a submission time count as submitted now, as in the full parse
+                let submission_time = parse_timestamp(&event, "Submission Time");
+                if options.since.is_none_or(|since| submission_time.is_none_or(|t| t >= since)) {
+                    job_count += 1;
+                }
+            }
+            _ => {}
+        }
+    }
     
-    Ok(SparkEventLog {
-        application_info,
-        jobs,
-        stages,
-        tasks,
-        executors,
-        environment,
-        sql_executions,
+    let application_info = application_info.context("No application start event found in event log")?;
+    Ok(LogFileSummary {
+        duration_ms: application_info.end_time
+            .map(|end| (end - application_info.start_time).num_milliseconds().max(0) as u64),
+        app_name: application_info.app_name,
+        app_id: application_info.app_id,
+        job_count,
     })
 }
 
+fn collect_log_files(dir: &Path, recursive: bool, paths: &mut Vec<PathBuf>) -> Result<()> {
+    let entries = std::fs::read_dir(dir)
+        .with_context(|| format!("Failed to read log directory: {}", dir.display()))?;
+    
+    for entry in entries {
+        let path = entry?.path();
+        let is_hidden = path.file_name()
+            .and_then(|n| n.to_str())
+            .is_some_and(|n| n.starts_with('.'));
+        if is_hidden {
+            continue;
+        }
+        
+        if path.is_dir() {
+            if recursive {
+                collect_log_files(&path, recursive, paths)?;
+            }
+        } else {
+            paths.push(path);
+        }
+    }
+    
+    Ok(())
+}
+
 fn parse_application_start(event: &Value) -> Result<ApplicationInfo> {
     let app_name = event.get("App Name")
         .and_then(|v| v.as_str())
@@ -146,7 +990,7 @@ fn parse_application_start(event: &Value) -> Result<ApplicationInfo> {
         .to_string();
     
     let start_time = parse_timestamp(event, "Timestamp")
-        .unwrap_or_else(|| Utc::now());
+        .unwrap_or_else(Utc::now);
     
     let user = event.get("User")
         .and_then(|v| v.as_str())
@@ -164,6 +1008,7 @@ fn parse_application_start(event: &Value) -> Result<ApplicationInfo> {
         app_attempt_id: event.get("App Attempt ID").and_then(|v| v.as_str()).map(|s| s.to_string()),
         start_time,
         end_time: None,
+        exit_code: None,
         user,
         spark_version,
     })
@@ -175,33 +1020,94 @@ fn parse_job_start(event: &Value) -> Result<Job> {
         .context("Missing Job ID")?;
     
     let submission_time = parse_timestamp(event, "Submission Time")
-        .unwrap_or_else(|| Utc::now());
+        .unwrap_or_else(Utc::now);
+    
+    let stage_infos = event.get("Stage Infos")
+        .and_then(|v| v.as_array())
+        .map(|arr| arr.as_slice())
+        .unwrap_or_default();
     
     let stage_ids = event.get("Stage IDs")
         .and_then(|v| v.as_array())
         .map(|arr| arr.iter().filter_map(|v| v.as_u64()).collect())
-        .unwrap_or_default();
+        .unwrap_or_else(|| stage_infos.iter().filter_map(|info| info.get("Stage ID").and_then(|v| v.as_u64())).collect());
+    
+    // Known before any task runs, so the jobs table has a denominator right away
+    let stage_task_counts: HashMap<u64, u64> = stage_infos.iter()
+        .filter_map(|info| {
+            let stage_id = info.get("Stage ID").and_then(|v| v.as_u64())?;
+            let num_tasks = info.get("Number of Tasks").and_then(|v| v.as_u64())?;
+            Some((stage_id, num_tasks))
+        })
+        .collect();
+    let num_tasks = stage_task_counts.values().sum();
+    
+    let properties = parse_properties(event, "Properties");
+    let description = properties.get("spark.job.description").cloned();
+    let sql_execution_id = properties.get("spark.sql.execution.id")
+        .and_then(|id| id.parse().ok());
     
     Ok(Job {
         job_id,
         name: format!("Job {}", job_id),
-        description: None,
+        description,
         submission_time,
         completion_time: None,
         stage_ids,
         status: JobStatus::Running,
-        num_tasks: 0,
+        num_tasks,
         num_active_tasks: 0,
         num_completed_tasks: 0,
         num_skipped_tasks: 0,
         num_failed_tasks: 0,
+        stage_task_counts,
+        properties,
+        sql_execution_id,
+        failure_reason: None,
     })
 }
 
+/// Swap the task count a job start announced for a stage with the count the
+/// stage was actually submitted with, which can differ after re-planning.
+fn update_job_task_counts(jobs: &mut HashMap<u64, Job>, stages: &HashMap<(u64, u64), Stage>, stage: &Stage) {
+    // Retried attempts only rerun missing partitions; keep the first count
+    if stages.keys().any(|(stage_id, _)| *stage_id == stage.stage_id) {
+        return;
+    }
+    
+    for job in jobs.values_mut().filter(|job| job.stage_ids.contains(&stage.stage_id)) {
+        let announced = job.stage_task_counts.insert(stage.stage_id, stage.num_tasks).unwrap_or(0);
+        job.num_tasks = job.num_tasks.saturating_sub(announced) + stage.num_tasks;
+    }
+}
+
 fn parse_stage_submitted(event: &Value) -> Result<Stage> {
     let stage_info = event.get("Stage Info")
         .context("Missing Stage Info")?;
-    
+    parse_stage_info(stage_info, event)
+}
+
+/// The `Stage Infos` of a job start that already carry a `Failure Reason`,
+/// as failed stages.
+fn parse_failed_stage_infos(event: &Value) -> Vec<Stage> {
+    event.get("Stage Infos")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|stage_info| {
+            let reason = stage_info.get("Failure Reason").and_then(|v| v.as_str())?;
+            let mut stage = parse_stage_info(stage_info, event).ok()?;
+            stage.status = StageStatus::Failed;
+            stage.completion_time = parse_timestamp(stage_info, "Completion Time");
+            stage.failure_reason = Some(reason.to_string());
+            Some(stage)
+        })
+        .collect()
+}
+
+/// A stage from a `Stage Info` object. Properties are read from `event`
+/// when the stage info has none of its own.
+fn parse_stage_info(stage_info: &Value, event: &Value) -> Result<Stage> {
     let stage_id = stage_info.get("Stage ID")
         .and_then(|v| v.as_u64())
         .context("Missing Stage ID")?;
@@ -231,6 +1137,20 @@ fn parse_stage_submitted(event: &Value) -> Result<Stage> {
         .map(|arr| arr.iter().filter_map(|v| parse_rdd_info(v).ok()).collect())
         .unwrap_or_default();
     
+    let details = stage_info.get("Details")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    
+    // Spark writes the properties next to "Stage Info", but accept them inside it too
+    let properties = if stage_info.get("Properties").is_some() {
+        parse_properties(stage_info, "Properties")
+    } else {
+        parse_properties(event, "Properties")
+    };
+    let sql_execution_id = properties.get("spark.sql.execution.id")
+        .and_then(|id| id.parse().ok());
+    
     Ok(Stage {
         stage_id,
         stage_attempt_id,
@@ -242,6 +1162,34 @@ fn parse_stage_submitted(event: &Value) -> Result<Stage> {
         completion_time: None,
         status: StageStatus::Active,
         task_metrics: None,
+        properties,
+        sql_execution_id,
+        details,
+        executor_peak_metrics: HashMap::new(),
+        failure_reason: None,
+        task_binary_size: stage_info.get("Task Binary File Size").and_then(|v| v.as_u64()),
+    })
+}
+
+fn parse_stage_executor_metrics(event: &Value) -> Result<StageExecutorMetrics> {
+    let executor_id = event.get("Executor ID")
+        .and_then(|v| v.as_str())
+        .context("Missing Executor ID")?
+        .to_string();
+    
+    let stage_id = event.get("Stage ID")
+        .and_then(|v| v.as_u64())
+        .context("Missing Stage ID")?;
+    
+    let peaks = event.get("Executor Metrics");
+    let peak = |key: &str| peaks.and_then(|m| m.get(key)).and_then(|v| v.as_u64()).unwrap_or(0);
+    
+    Ok(StageExecutorMetrics {
+        executor_id,
+        stage_id,
+        peak_jvm_used_heap: peak("JVMHeapMemory"),
+        peak_jvm_used_non_heap: peak("JVMOffHeapMemory"),
+        peak_process_tree_rss_bytes: peak("ProcessTreeJVMRSSMemory"),
     })
 }
 
@@ -253,11 +1201,14 @@ fn parse_task_start(event: &Value) -> Result<Task> {
         .and_then(|v| v.as_u64())
         .context("Missing Task ID")?;
     
+    // Spark 2.x only records the stage on the event itself, not in Task Info
     let stage_id = task_info.get("Stage ID")
+        .or_else(|| event.get("Stage ID"))
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     
     let stage_attempt_id = task_info.get("Stage Attempt ID")
+        .or_else(|| event.get("Stage Attempt ID"))
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     
@@ -265,6 +1216,10 @@ fn parse_task_start(event: &Value) -> Result<Task> {
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     
+    let task_index = task_info.get("Index")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    
     let executor_id = task_info.get("Executor ID")
         .and_then(|v| v.as_str())
         .unwrap_or("unknown")
@@ -276,24 +1231,168 @@ fn parse_task_start(event: &Value) -> Result<Task> {
         .to_string();
     
     let launch_time = parse_timestamp(task_info, "Launch Time")
-        .unwrap_or_else(|| Utc::now());
+        .unwrap_or_else(Utc::now);
     
     Ok(Task {
         task_id,
         stage_id,
         stage_attempt_id,
         partition_id,
+        task_index,
         executor_id,
         host,
         launch_time,
         finish_time: None,
         status: TaskStatus::Running,
+        failure_reason: None,
         metrics: None,
     })
 }
 
-fn parse_executor_added(event: &Value) -> Result<Executor> {
-    let executor_id = event.get("Executor ID")
+fn parse_node_added(event: &Value) -> Result<ClusterNode> {
+    let host = event.get("Host")
+        .and_then(|v| v.as_str())
+        .context("Missing Host")?
+        .to_string();
+    
+    let port = event.get("Port")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u16;
+    
+    let cores = event.get("Cores")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    
+    Ok(ClusterNode {
+        host,
+        port,
+        cores,
+        is_active: true,
+    })
+}
+
+fn parse_worker_added(event: &Value) -> Result<Worker> {
+    let worker_id = event.get("Worker Id")
+        .and_then(|v| v.as_str())
+        .context("Missing Worker Id")?
+        .to_string();
+    
+    let host = event.get("Host")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+    
+    let port = event.get("Port")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u16;
+    
+    let cores = event.get("Cores")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0) as u32;
+    
+    let memory = event.get("Memory")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    
+    Ok(Worker {
+        worker_id,
+        host,
+        port,
+        cores,
+        memory,
+        is_active: true,
+    })
+}
+
+fn parse_block_manager_migration(event: &Value) -> Result<BlockManagerMigration> {
+    let source_executor_id = event.get("Source Executor ID")
+        .and_then(|v| v.as_str())
+        .context("Missing Source Executor ID")?
+        .to_string();
+    
+    // The driver is the one taking ownership unless the event says otherwise
+    let target_executor_id = event.get("Target Executor ID")
+        .and_then(|v| v.as_str())
+        .unwrap_or("driver")
+        .to_string();
+    
+    let num_blocks = event.get("Number of Blocks")
+        .and_then(|v| v.as_u64())
+        .unwrap_or(0);
+    
+    Ok(BlockManagerMigration {
+        source_executor_id,
+        target_executor_id,
+        timestamp: parse_timestamp(event, "Timestamp"),
+        num_blocks,
+    })
+}
+
+fn parse_misc_process(event: &Value) -> Result<MiscProcess> {
+    let name = event.get("processId")
+        .and_then(|v| v.as_str())
+        .context("Missing processId")?
+        .to_string();
+    
+    // The address comes as "host:port"
+    let host_port = event.get("info")
+        .and_then(|info| info.get("hostPort"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("");
+    let (host, port) = match host_port.rsplit_once(':') {
+        Some((host, port)) => (host.to_string(), port.parse().unwrap_or(0)),
+        None => (host_port.to_string(), 0),
+    };
+    
+    Ok(MiscProcess {
+        name,
+        host,
+        port,
+        timestamp: parse_timestamp(event, "time"),
+    })
+}
+
+fn parse_resource_profile(event: &Value) -> Result<ResourceProfile> {
+    let profile_id = event.get("Resource Profile Id")
+        .and_then(|v| v.as_u64())
+        .context("Missing Resource Profile Id")? as u32;
+    
+    // Each request is an object keyed by resource name carrying an "Amount"
+    let amounts = |key: &str| -> Vec<(String, f64)> {
+        event.get(key)
+            .and_then(|v| v.as_object())
+            .into_iter()
+            .flatten()
+            .filter_map(|(name, request)| {
+                request.get("Amount").and_then(|v| v.as_f64()).map(|amount| (name.clone(), amount))
+            })
+            .collect()
+    };
+    
+    Ok(ResourceProfile {
+        profile_id,
+        executor_resources: amounts("Executor Resource Requests")
+            .into_iter()
+            .map(|(name, amount)| (name, amount as u64))
+            .collect(),
+        task_resources: amounts("Task Resource Requests").into_iter().collect(),
+    })
+}
+
+fn parse_unschedulable_task_set(event: &Value) -> Result<UnschedulableEvent> {
+    let stage_id = event.get("stageId")
+        .and_then(|v| v.as_u64())
+        .context("Missing stageId")?;
+    
+    Ok(UnschedulableEvent {
+        stage_id,
+        stage_attempt_id: event.get("stageAttemptId").and_then(|v| v.as_u64()).unwrap_or(0),
+        timestamp: parse_timestamp(event, "time"),
+    })
+}
+
+fn parse_executor_added(event: &Value) -> Result<Executor> {
+    let executor_id = event.get("Executor ID")
         .and_then(|v| v.as_str())
         .context("Missing Executor ID")?
         .to_string();
@@ -306,6 +1405,13 @@ fn parse_executor_added(event: &Value) -> Result<Executor> {
         .unwrap_or("unknown")
         .to_string();
     
+    // Depending on the Spark version the RPC port sits in Executor Info or on the event
+    let port = executor_info.get("Port")
+        .or_else(|| event.get("Port"))
+        .and_then(|v| v.as_u64())
+        .and_then(|port| u16::try_from(port).ok())
+        .unwrap_or(0);
+    
     let total_cores = executor_info.get("Total Cores")
         .and_then(|v| v.as_u64())
         .unwrap_or(1) as u32;
@@ -314,10 +1420,16 @@ fn parse_executor_added(event: &Value) -> Result<Executor> {
         .and_then(|v| v.as_u64())
         .unwrap_or(0);
     
+    let attributes = parse_properties(executor_info, "Attributes");
+    let k8s_attributes = attributes.iter()
+        .filter(|(key, _)| key.starts_with("kubernetes."))
+        .map(|(key, value)| (key.clone(), value.clone()))
+        .collect();
+    
     Ok(Executor {
         executor_id,
         host,
-        port: 0,
+        port,
         is_active: true,
         total_cores,
         max_tasks: total_cores,
@@ -335,9 +1447,28 @@ fn parse_executor_added(event: &Value) -> Result<Executor> {
         max_off_heap_memory: 0,
         memory_used: 0,
         disk_used: 0,
+        memory_samples: Vec::new(),
+        resource_profile_id: executor_info.get("Resource Profile Id").and_then(|v| v.as_u64()).map(|id| id as u32),
+        attributes,
+        k8s_attributes,
+        resources: parse_executor_resources(executor_info),
     })
 }
 
+/// JVM heap usage samples carried by a `SparkListenerExecutorMetricsUpdate`.
+fn parse_heap_samples(event: &Value) -> Vec<u64> {
+    let per_stage = event.get("Executor Metrics Updated")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|update| update.get("Executor Metrics"));
+    
+    per_stage
+        .chain(event.get("Executor Metrics"))
+        .filter_map(|metrics| metrics.get("JVMHeapMemory").and_then(|v| v.as_u64()))
+        .collect()
+}
+
 fn parse_environment_update(event: &Value) -> Result<Environment> {
     let spark_properties = parse_properties(event, "Spark Properties");
     let hadoop_properties = parse_properties(event, "Hadoop Properties");
@@ -363,6 +1494,66 @@ fn parse_properties(event: &Value, key: &str) -> HashMap<String, String> {
         .unwrap_or_default()
 }
 
+/// The executor ID with its on-heap and off-heap storage memory limits from a
+/// `SparkListenerBlockManagerAdded`. Spark writes them as top-level
+/// "Maximum Onheap/Offheap Memory"; some builds nest them in Executor Info.
+fn parse_block_manager_added(event: &Value) -> Option<BlockManager> {
+    let block_manager_id = event.get("Block Manager ID")?;
+    let executor_id = block_manager_id.get("Executor ID")
+        .and_then(|v| v.as_str())?
+        .to_string();
+    
+    let executor_info = event.get("Executor Info");
+    let memory = |top_level: &str, nested: &str| {
+        event.get(top_level)
+            .or_else(|| executor_info.and_then(|info| info.get(nested)))
+            .and_then(|v| v.as_u64())
+            .unwrap_or(0)
+    };
+    
+    let max_memory = event.get("Maximum Memory").and_then(|v| v.as_u64()).unwrap_or(0);
+    let max_off_heap_memory = memory("Maximum Offheap Memory", "Max Off Heap Memory");
+    // Before Spark 2.3 there is no on/off-heap split and everything is on-heap
+    let max_on_heap_memory = match memory("Maximum Onheap Memory", "Max On Heap Memory") {
+        0 => max_memory.saturating_sub(max_off_heap_memory),
+        on_heap => on_heap,
+    };
+    
+    Some(BlockManager {
+        executor_id,
+        host: block_manager_id.get("Host").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+        port: block_manager_id.get("Port")
+            .and_then(|v| v.as_u64())
+            .and_then(|port| u16::try_from(port).ok())
+            .unwrap_or(0),
+        max_memory,
+        max_on_heap_memory,
+        max_off_heap_memory,
+    })
+}
+
+/// `Executor Info.Resources`, e.g. `{"gpu": {"name": "gpu", "addresses": ["0", "1"]}}`.
+/// Spark only writes the addresses, so the amount is their count unless an
+/// explicit `amount` is given.
+fn parse_executor_resources(executor_info: &Value) -> HashMap<String, ResourceAmount> {
+    let Some(resources) = executor_info.get("Resources").and_then(|v| v.as_object()) else {
+        return HashMap::new();
+    };
+    
+    resources.iter()
+        .map(|(name, info)| {
+            let addresses: Vec<String> = info.get("addresses")
+                .and_then(|v| v.as_array())
+                .map(|arr| arr.iter().filter_map(|v| v.as_str()).map(|s| s.to_string()).collect())
+                .unwrap_or_default();
+            let amount = info.get("amount")
+                .and_then(|v| v.as_u64())
+                .unwrap_or(addresses.len() as u64);
+            (name.clone(), ResourceAmount { amount, addresses })
+        })
+        .collect()
+}
+
 fn parse_rdd_info(value: &Value) -> Result<RddInfo> {
     let rdd_id = value.get("RDD ID")
         .and_then(|v| v.as_u64())
@@ -387,13 +1578,40 @@ fn parse_rdd_info(value: &Value) -> Result<RddInfo> {
 fn parse_task_metrics(event: &Value) -> Option<TaskMetrics> {
     let metrics = event.get("Task Metrics")?;
     
-    Some(TaskMetrics {
-        execution_time: metrics.get("Executor Run Time").and_then(|v| v.as_u64()).unwrap_or(0),
+    let execution_time = metrics.get("Executor Run Time").and_then(|v| v.as_u64()).unwrap_or(0);
+    let deserialization_time = metrics.get("Executor Deserialize Time").and_then(|v| v.as_u64()).unwrap_or(0);
+    let result_serialization_time = metrics.get("Result Serialization Time").and_then(|v| v.as_u64()).unwrap_or(0);
+    
+    let task_info = event.get("Task Info");
+    let launch_time = task_info.and_then(|t| t.get("Launch Time")).and_then(|v| v.as_u64()).unwrap_or(0);
+    let finish_time = task_info.and_then(|t| t.get("Finish Time")).and_then(|v| v.as_u64()).unwrap_or(0);
+    let result_getting_time = match task_info.and_then(|t| t.get("Getting Result Time")).and_then(|v| v.as_u64()) {
+        Some(start) if start > 0 && finish_time >= start => finish_time - start,
+        _ => 0,
+    };
+    
+    // Same derivation as the Spark UI: whatever part of the task's wall time
+    // was not spent running, (de)serializing or fetching the result
+    let scheduler_delay = if task_info.is_some() {
+        finish_time.saturating_sub(launch_time)
+            .saturating_sub(execution_time)
+            .saturating_sub(deserialization_time)
+            .saturating_sub(result_serialization_time)
+            .saturating_sub(result_getting_time)
+    } else {
+        0
+    };
+    
+    let mut task_metrics = TaskMetrics {
+        execution_time,
         cpu_time: metrics.get("Executor CPU Time").and_then(|v| v.as_u64()).unwrap_or(0),
         gc_time: metrics.get("JVM GC Time").and_then(|v| v.as_u64()).unwrap_or(0),
         result_size: metrics.get("Result Size").and_then(|v| v.as_u64()).unwrap_or(0),
         jvm_gc_time: metrics.get("JVM GC Time").and_then(|v| v.as_u64()).unwrap_or(0),
-        result_serialization_time: metrics.get("Result Serialization Time").and_then(|v| v.as_u64()).unwrap_or(0),
+        result_serialization_time,
+        deserialization_time,
+        scheduler_delay,
+        result_getting_time,
         memory_bytes_spilled: metrics.get("Memory Bytes Spilled").and_then(|v| v.as_u64()).unwrap_or(0),
         disk_bytes_spilled: metrics.get("Disk Bytes Spilled").and_then(|v| v.as_u64()).unwrap_or(0),
         peak_execution_memory: metrics.get("Peak Execution Memory").and_then(|v| v.as_u64()).unwrap_or(0),
@@ -401,7 +1619,87 @@ fn parse_task_metrics(event: &Value) -> Option<TaskMetrics> {
         output_metrics: parse_output_metrics(metrics),
         shuffle_read_metrics: parse_shuffle_read_metrics(metrics),
         shuffle_write_metrics: parse_shuffle_write_metrics(metrics),
-    })
+    };
+    if let Some(task_info) = task_info {
+        fill_from_internal_accumulators(&mut task_metrics, &parse_internal_accumulators(task_info));
+    }
+    Some(task_metrics)
+}
+
+/// Accumulator IDs and values from a Stage Info "Accumulables" list.
+fn parse_stage_accumulables(stage_info: &Value) -> Vec<(u64, u64)> {
+    stage_info.get("Accumulables")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|accumulable| {
+            let id = accumulable.get("ID").and_then(|v| v.as_u64())?;
+            // Older versions write the value as a string
+            let value = accumulable.get("Value")?;
+            let value = value.as_u64().or_else(|| value.as_str().and_then(|s| s.parse().ok()))?;
+            Some((id, value))
+        })
+        .collect()
+}
+
+/// Per-task updates of Spark's `internal.metrics.*` accumulators, from the
+/// Task Info "Accumulables" list.
+fn parse_internal_accumulators(task_info: &Value) -> HashMap<String, u64> {
+    task_info.get("Accumulables")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(|accumulable| {
+            let name = accumulable.get("Name").and_then(|v| v.as_str())?;
+            let name = name.strip_prefix("internal.metrics.")?;
+            // Older versions write the update as a string
+            let update = accumulable.get("Update")?;
+            let update = update.as_u64().or_else(|| update.as_str().and_then(|s| s.parse().ok()))?;
+            Some((name.to_string(), update))
+        })
+        .collect()
+}
+
+/// Older Spark versions leave some fields out of "Task Metrics" while still
+/// reporting them as internal accumulators; fill in any that came out as 0.
+fn fill_from_internal_accumulators(metrics: &mut TaskMetrics, accumulators: &HashMap<String, u64>) {
+    let fill = |field: &mut u64, name: &str| {
+        if *field == 0 {
+            *field = accumulators.get(name).copied().unwrap_or(0);
+        }
+    };
+    let reported = |prefix: &str| accumulators.keys().any(|name| name.starts_with(prefix));
+    
+    fill(&mut metrics.result_size, "resultSize");
+    fill(&mut metrics.memory_bytes_spilled, "memoryBytesSpilled");
+    fill(&mut metrics.disk_bytes_spilled, "diskBytesSpilled");
+    fill(&mut metrics.peak_execution_memory, "peakExecutionMemory");
+    
+    if reported("input.") {
+        let input = metrics.input_metrics.get_or_insert_with(InputMetrics::default);
+        fill(&mut input.bytes_read, "input.bytesRead");
+        fill(&mut input.records_read, "input.recordsRead");
+    }
+    if reported("output.") {
+        let output = metrics.output_metrics.get_or_insert_with(OutputMetrics::default);
+        fill(&mut output.bytes_written, "output.bytesWritten");
+        fill(&mut output.records_written, "output.recordsWritten");
+    }
+    if reported("shuffle.read.") {
+        let shuffle_read = metrics.shuffle_read_metrics.get_or_insert_with(ShuffleReadMetrics::default);
+        fill(&mut shuffle_read.remote_blocks_fetched, "shuffle.read.remoteBlocksFetched");
+        fill(&mut shuffle_read.local_blocks_fetched, "shuffle.read.localBlocksFetched");
+        fill(&mut shuffle_read.fetch_wait_time, "shuffle.read.fetchWaitTime");
+        fill(&mut shuffle_read.remote_bytes_read, "shuffle.read.remoteBytesRead");
+        fill(&mut shuffle_read.local_bytes_read, "shuffle.read.localBytesRead");
+        fill(&mut shuffle_read.records_read, "shuffle.read.recordsRead");
+    }
+    if reported("shuffle.write.") {
+        let shuffle_write = metrics.shuffle_write_metrics.get_or_insert_with(ShuffleWriteMetrics::default);
+        fill(&mut shuffle_write.bytes_written, "shuffle.write.bytesWritten");
+        fill(&mut shuffle_write.write_time, "shuffle.write.writeTime");
+        fill(&mut shuffle_write.records_written, "shuffle.write.recordsWritten");
+    }
 }
 
 fn parse_input_metrics(metrics: &Value) -> Option<InputMetrics> {
@@ -422,6 +1720,19 @@ fn parse_output_metrics(metrics: &Value) -> Option<OutputMetrics> {
 
 fn parse_shuffle_read_metrics(metrics: &Value) -> Option<ShuffleReadMetrics> {
     let shuffle_read = metrics.get("Shuffle Read Metrics")?;
+    // Spark nests the merged counters under "Push Based Shuffle" as
+    // "Merged Remote Blocks Fetched" and so on; accept flat names too
+    let push = shuffle_read.get("Push Based Shuffle");
+    let merged = |flat: &str, nested: &str| {
+        shuffle_read.get(flat)
+            .or_else(|| push.and_then(|p| p.get(nested)))
+            .and_then(|v| v.as_u64())
+    };
+    let remote_merged_blocks_fetched = merged("Remote Merged Blocks Fetched", "Merged Remote Blocks Fetched").unwrap_or(0);
+    let local_merged_blocks_fetched = merged("Local Merged Blocks Fetched", "Merged Local Blocks Fetched").unwrap_or(0);
+    let remote_merged_bytes_read = merged("Remote Merged Bytes Read", "Merged Remote Bytes Read").unwrap_or(0);
+    let local_merged_bytes_read = merged("Local Merged Bytes Read", "Merged Local Bytes Read").unwrap_or(0);
+    
     Some(ShuffleReadMetrics {
         remote_blocks_fetched: shuffle_read.get("Remote Blocks Fetched").and_then(|v| v.as_u64()).unwrap_or(0),
         local_blocks_fetched: shuffle_read.get("Local Blocks Fetched").and_then(|v| v.as_u64()).unwrap_or(0),
@@ -429,6 +1740,14 @@ fn parse_shuffle_read_metrics(metrics: &Value) -> Option<ShuffleReadMetrics> {
         remote_bytes_read: shuffle_read.get("Remote Bytes Read").and_then(|v| v.as_u64()).unwrap_or(0),
         local_bytes_read: shuffle_read.get("Local Bytes Read").and_then(|v| v.as_u64()).unwrap_or(0),
         records_read: shuffle_read.get("Records Read").and_then(|v| v.as_u64()).unwrap_or(0),
+        // Spark 3.2+ writes the object on every task, zero-filled when push
+        // shuffle is off, so only non-zero counters mean it was used
+        push_based_shuffle: remote_merged_blocks_fetched + local_merged_blocks_fetched > 0
+            || remote_merged_bytes_read + local_merged_bytes_read > 0,
+        remote_merged_blocks_fetched,
+        local_merged_blocks_fetched,
+        remote_merged_bytes_read,
+        local_merged_bytes_read,
     })
 }
 
@@ -441,6 +1760,12 @@ fn parse_shuffle_write_metrics(metrics: &Value) -> Option<ShuffleWriteMetrics> {
     })
 }
 
+/// The time an event happened at, for the events that carry one at the top level.
+fn event_time(event: &Value) -> Option<DateTime<Utc>> {
+    ["Timestamp", "time", "Submission Time", "Completion Time"].iter()
+        .find_map(|key| parse_timestamp(event, key))
+}
+
 fn parse_timestamp(event: &Value, key: &str) -> Option<DateTime<Utc>> {
     event.get(key)
         .and_then(|v| v.as_u64())
@@ -457,6 +1782,24 @@ fn parse_job_result(event: &Value) -> JobStatus {
     }
 }
 
+/// Message of the exception a `JobFailed` result carries. Spark nests it as
+/// `Exception.Message`; some writers put `Message` on the result itself.
+fn parse_job_failure_reason(event: &Value) -> Option<String> {
+    let result = event.get("Job Result")?;
+    if result.get("Result").and_then(|v| v.as_str()) != Some("JobFailed") {
+        return None;
+    }
+    
+    let message = match result.get("Exception") {
+        Some(Value::String(message)) => Some(message.as_str()),
+        Some(exception) => exception.get("Message").and_then(|v| v.as_str()),
+        None => None,
+    };
+    message
+        .or_else(|| result.get("Message").and_then(|v| v.as_str()))
+        .map(|message| message.to_string())
+}
+
 fn parse_task_status(task_info: &Value) -> TaskStatus {
     if task_info.get("Failed").and_then(|v| v.as_bool()).unwrap_or(false) {
         TaskStatus::Failed
@@ -469,6 +1812,25 @@ fn parse_task_status(task_info: &Value) -> TaskStatus {
     }
 }
 
+/// `"Class: description"` for a task that did not succeed, e.g.
+/// `"java.lang.OutOfMemoryError: Java heap space"`.
+fn parse_task_failure_reason(event: &Value) -> Option<String> {
+    let reason = event.get("Task End Reason")?;
+    let kind = reason.get("Reason").and_then(|v| v.as_str())?;
+    if kind == "Success" {
+        return None;
+    }
+    
+    let description = reason.get("Description").and_then(|v| v.as_str());
+    let class_name = reason.get("Class Name").and_then(|v| v.as_str());
+    Some(match (class_name, description) {
+        (Some(class_name), Some(description)) => format!("{}: {}", class_name, description),
+        (None, Some(description)) => description.to_string(),
+        (Some(class_name), None) => class_name.to_string(),
+        (None, None) => kind.to_string(),
+    })
+}
+
 fn parse_sql_execution_start(event: &Value) -> Result<crate::models::SqlExecution> {
     let execution_id = event.get("executionId")
         .and_then(|v| v.as_u64())
@@ -484,24 +1846,1387 @@ fn parse_sql_execution_start(event: &Value) -> Result<crate::models::SqlExecutio
         .unwrap_or("")
         .to_string();
     
+    let plan_description = event.get("planDescription")
+        .and_then(|v| v.as_str())
+        .unwrap_or("")
+        .to_string();
+    
+    let run_id = event.get("runId")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string());
+    
     let physical_plan = event.get("physicalPlanDescription")
         .and_then(|v| v.as_str())
         .unwrap_or("")
         .to_string();
     
     let submission_time = parse_timestamp(event, "time")
-        .unwrap_or_else(|| Utc::now());
+        .unwrap_or_else(Utc::now);
+    
+    // Older versions put the formatted plan in planDescription
+    let plan_operators = [&plan_description, &physical_plan].into_iter()
+        .map(|plan| parse_plan_operators(plan))
+        .find(|operators| !operators.is_empty())
+        .unwrap_or_default();
+    
+    // Metrics are declared on the plan nodes, and in some versions in a
+    // top-level "metrics" list as well
+    let mut metric_definitions: Vec<_> = event.get("metrics")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(parse_metric_definition)
+        .collect();
+    let mut input_rdd_ids = Vec::new();
+    if let Some(plan_info) = event.get("sparkPlanInfo") {
+        collect_plan_metric_definitions(plan_info, &mut metric_definitions);
+        collect_plan_input_rdds(plan_info, &mut input_rdd_ids);
+    }
     
-    Ok(crate::models::SqlExecution {
+    let mut sql_execution = crate::models::SqlExecution {
         execution_id,
+        run_id,
         description,
         details,
-        physical_plan_description: physical_plan,
+        plan_description,
+        initial_physical_plan: physical_plan.clone(),
+        physical_plan,
         submission_time,
         completion_time: None,
         status: crate::models::SqlExecutionStatus::Running,
         jobs: Vec::new(),
         stages: Vec::new(),
         metrics: Vec::new(),
+        metric_definitions: Vec::new(),
+        modified_configs: parse_properties(event, "modifiedConfigs"),
+        aqe_metric_updates: Vec::new(),
+        metric_names: HashMap::new(),
+        input_rdd_ids,
+        error_message: None,
+        aqe_coalesced_partitions: None,
+        aqe_initial_partitions: None,
+        has_skew_join: false,
+        aqe_broadcast_joins_added: 0,
+        plan_operators,
+    };
+    record_metric_definitions(&mut sql_execution, metric_definitions);
+    Ok(sql_execution)
+}
+
+/// `{"name": ..., "accumulatorId": ..., "metricType": ...}`; the type
+/// defaults to a plain sum.
+fn parse_metric_definition(metric: &Value) -> Option<SqlMetricDefinition> {
+    Some(SqlMetricDefinition {
+        accumulator_id: metric.get("accumulatorId").and_then(|v| v.as_u64())?,
+        name: metric.get("name").and_then(|v| v.as_str())?.to_string(),
+        metric_type: metric.get("metricType").and_then(|v| v.as_str()).unwrap_or("sum").to_string(),
     })
-}
\ No newline at end of file
+}
+
+/// Walk a `sparkPlanInfo` node and its children, collecting every metric
+/// they declare.
+fn collect_plan_metric_definitions(node: &Value, definitions: &mut Vec<SqlMetricDefinition>) {
+    if let Some(metrics) = node.get("metrics").and_then(|v| v.as_array()) {
+        definitions.extend(metrics.iter().filter_map(parse_metric_definition));
+    }
+    
+    if let Some(children) = node.get("children").and_then(|v| v.as_array()) {
+        for child in children {
+            collect_plan_metric_definitions(child, definitions);
+        }
+    }
+}
+
+/// Add metrics from a (re-)planned query, naming their accumulators and
+/// skipping ones an earlier plan already declared.
+fn record_metric_definitions(sql_execution: &mut SqlExecution, definitions: Vec<SqlMetricDefinition>) {
+    for definition in definitions {
+        sql_execution.metric_names.insert(definition.accumulator_id, definition.name.clone());
+        if !sql_execution.metric_definitions.iter().any(|d| d.accumulator_id == definition.accumulator_id) {
+            sql_execution.metric_definitions.push(definition);
+        }
+    }
+}
+
+/// Record the `inputRDDs` IDs of the leaf nodes of a `sparkPlanInfo` tree,
+/// skipping ones already seen from an earlier plan.
+fn collect_plan_input_rdds(node: &Value, rdd_ids: &mut Vec<u64>) {
+    let children = node.get("children").and_then(|v| v.as_array()).filter(|c| !c.is_empty());
+    match children {
+        Some(children) => {
+            for child in children {
+                collect_plan_input_rdds(child, rdd_ids);
+            }
+        }
+        None => {
+            let inputs = node.get("inputRDDs").and_then(|v| v.as_array()).into_iter().flatten();
+            for id in inputs.filter_map(|v| v.as_u64()) {
+                if !rdd_ids.contains(&id) {
+                    rdd_ids.push(id);
+                }
+            }
+        }
+    }
+}
+
+/// Partitions read by the AQE shuffle readers in a `sparkPlanInfo` tree:
+/// the length of each `CustomShuffleReader` node's `partitionSpecs`
+/// (`AQEShuffleRead` from Spark 3.2), summed. `None` when the plan has no
+/// such reader, i.e. nothing was coalesced.
+fn coalesced_partition_count(node: &Value) -> Option<u64> {
+    let own = if is_aqe_shuffle_reader(node) {
+        node.get("partitionSpecs").and_then(|v| v.as_array()).map(|specs| specs.len() as u64)
+    } else {
+        None
+    };
+    
+    node.get("children")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .filter_map(coalesced_partition_count)
+        .chain(own)
+        .reduce(|a, b| a + b)
+}
+
+/// Operator names from the numbered `(N) Name [codegen id : 1]` entries of
+/// the "== Physical Plan ==" section of a plan in formatted explain mode.
+fn parse_plan_operators(plan: &str) -> Vec<String> {
+    plan.lines()
+        .skip_while(|line| line.trim() != "== Physical Plan ==")
+        .skip(1)
+        .take_while(|line| !line.starts_with("== "))
+        .filter_map(|line| {
+            let (number, rest) = line.trim().strip_prefix('(')?.split_once(") ")?;
+            number.parse::<u32>().ok()?;
+            let name = rest.split(" [").next().unwrap_or(rest).trim();
+            (!name.is_empty()).then(|| name.to_string())
+        })
+        .collect()
+}
+
+/// Whether an AQE shuffle reader in a `sparkPlanInfo` tree reads skewed
+/// partitions: flagged with `isSkew`, named "... skewed", or carrying the
+/// "number of skewed partitions" metric.
+fn plan_has_skew_join(node: &Value) -> bool {
+    let is_skewed = |v: &Value| v.get("isSkew").and_then(|v| v.as_bool()).unwrap_or(false);
+    let skewed_reader = is_aqe_shuffle_reader(node) && (
+        is_skewed(node)
+        || node.get("nodeName").and_then(|v| v.as_str()).is_some_and(|name| name.contains("skewed"))
+        || node.get("metrics").and_then(|v| v.as_array()).into_iter().flatten().any(|metric| {
+            is_skewed(metric) || metric.get("name").and_then(|v| v.as_str()) == Some("number of skewed partitions")
+        })
+    );
+    
+    skewed_reader || node.get("children")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .any(plan_has_skew_join)
+}
+
+/// `BroadcastHashJoin` nodes in a `sparkPlanInfo` tree.
+fn count_broadcast_joins(node: &Value) -> u32 {
+    let own = node.get("nodeName").and_then(|v| v.as_str()) == Some("BroadcastHashJoin");
+    let children: u32 = node.get("children")
+        .and_then(|v| v.as_array())
+        .into_iter()
+        .flatten()
+        .map(count_broadcast_joins)
+        .sum();
+    children + own as u32
+}
+
+fn is_aqe_shuffle_reader(node: &Value) -> bool {
+    node.get("nodeName")
+        .and_then(|v| v.as_str())
+        .is_some_and(|name| name.starts_with("CustomShuffleReader") || name.starts_with("AQEShuffleRead"))
+}
+
+/// Named metric values from a `SparkListenerSQLAdaptiveSQLMetricUpdates`.
+/// Unnamed entries are looked up in `metric_names` by accumulator ID, and
+/// entries without a value (plain metric declarations, which is what Spark
+/// writes) are skipped here and valued by `fill_aqe_metric_updates`.
+fn parse_aqe_metric_updates(event: &Value, metric_names: &HashMap<u64, String>) -> Vec<(String, i64)> {
+    event.get("sqlPlanMetrics")
+        .and_then(|v| v.as_array())
+        .map(|metrics| {
+            metrics.iter()
+                .filter_map(|metric| {
+                    let value = metric.get("value").and_then(|v| v.as_i64())?;
+                    let name = match metric.get("name").and_then(|v| v.as_str()) {
+                        Some(name) => name.to_string(),
+                        None => {
+                            let id = metric.get("accumulatorId").and_then(|v| v.as_u64())?;
+                            metric_names.get(&id).cloned().unwrap_or_else(|| format!("accumulator {}", id))
+                        }
+                    };
+                    Some((name, value))
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Write `lines` to a temporary event log named after `name` and parse it.
+    fn parse_fixture(name: &str, lines: &[&str]) -> Result<(SparkEventLog, ParseMetadata)> {
+        parse_fixture_with_options(name, lines, &ParseOptions::default())
+    }
+
+    fn parse_fixture_with_options(name: &str, lines: &[&str], options: &ParseOptions) -> Result<(SparkEventLog, ParseMetadata)> {
+        let path = std::env::temp_dir().join(format!("spark-tui-{}-{}.json", name, std::process::id()));
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        let result = parse_event_log_with_options(&path, options);
+        std::fs::remove_file(&path).unwrap();
+        result
+    }
+
+    #[test]
+    fn parse_event_log_replaces_invalid_utf8() {
+        let path = std::env::temp_dir().join(format!("spark-tui-invalid-utf8-{}.json", std::process::id()));
+        let mut contents = br#"{"Event":"SparkListenerApplicationStart","App Name":"Fixture "#.to_vec();
+        contents.push(0xff);
+        contents.extend_from_slice(br#"","App ID":"app-1","Timestamp":1733052000000,"User":"test"}"#);
+        contents.extend_from_slice(b"\r\n");
+        std::fs::write(&path, &contents).unwrap();
+        let result = parse_event_log(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let (event_log, _) = result.unwrap();
+        assert_eq!(event_log.application_info.app_name, "Fixture \u{fffd}");
+        assert!(event_log.parse_warnings.is_empty());
+    }
+
+    const APPLICATION_START: &str = r#"{"Event":"SparkListenerApplicationStart","App Name":"Fixture App","App ID":"app-1","Timestamp":1733052000000,"User":"test"}"#;
+
+    #[test]
+    fn parse_application_start_reads_all_fields() {
+        let event = json!({
+            "Event": "SparkListenerApplicationStart",
+            "App Name": "Fixture App",
+            "App ID": "app-20241201120000-0001",
+            "App Attempt ID": "2",
+            "Timestamp": 1733052000123u64,
+            "User": "alice",
+            "Spark Version": "3.5.0"
+        });
+
+        let app_info = parse_application_start(&event).unwrap();
+        assert_eq!(app_info.app_name, "Fixture App");
+        assert_eq!(app_info.app_id, "app-20241201120000-0001");
+        assert_eq!(app_info.app_attempt_id.as_deref(), Some("2"));
+        assert_eq!(app_info.start_time.timestamp_millis(), 1733052000123);
+        assert_eq!(app_info.end_time, None);
+        assert_eq!(app_info.user, "alice");
+        assert_eq!(app_info.spark_version, "3.5.0");
+    }
+
+    #[test]
+    fn parse_event_log_takes_spark_version_from_properties_when_missing() {
+        let (event_log, _) = parse_fixture("no-spark-version", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerEnvironmentUpdate","Spark Properties":{"spark.version":"2.4.8-amzn-0"}}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.application_info.spark_version, "2.4.8-amzn-0");
+        assert_eq!(event_log.application_attempts[0].spark_version, "2.4.8-amzn-0");
+    }
+
+    #[test]
+    fn parse_sql_execution_start_reads_modified_configs() {
+        let event = json!({
+            "Event": "org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionStart",
+            "executionId": 3,
+            "description": "select 1",
+            "time": 1733052001000u64,
+            "runId": "6b1f0c1e-6a0e-4c3e-9a57-2d3f4b1c8e90",
+            "modifiedConfigs": {"spark.sql.shuffle.partitions": "400"}
+        });
+
+        let execution = parse_sql_execution_start(&event).unwrap();
+        assert_eq!(execution.run_id.as_deref(), Some("6b1f0c1e-6a0e-4c3e-9a57-2d3f4b1c8e90"));
+        assert_eq!(execution.modified_configs.len(), 1);
+        assert_eq!(execution.modified_configs["spark.sql.shuffle.partitions"], "400");
+    }
+
+    #[test]
+    fn parse_sql_execution_start_lists_physical_plan_operators() {
+        let event = json!({
+            "Event": "SparkListenerSQLExecutionStart",
+            "executionId": 0,
+            "description": "q",
+            "time": 1733052001000u64,
+            "planDescription": "== Optimized Logical Plan ==\nProject [id#0]\n\n== Physical Plan ==\n* Project (2)\n+- Scan parquet default.t (1)\n\n(1) Scan parquet default.t\nOutput [1]: [id#0]\n\n(2) Project [codegen id : 1]\nInput [1]: [id#0]\n"
+        });
+
+        let execution = parse_sql_execution_start(&event).unwrap();
+        assert_eq!(execution.plan_operators, vec!["Scan parquet default.t", "Project"]);
+    }
+
+    #[test]
+    fn parse_event_log_reads_fully_qualified_sql_events() {
+        let (event_log, _) = parse_fixture("sql-qualified", &[
+            APPLICATION_START,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionEnd","executionId":0,"time":1733052002000}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert!(matches!(execution.status, SqlExecutionStatus::Completed));
+        assert_eq!(execution.completion_time.unwrap().timestamp_millis(), 1733052002000);
+    }
+
+    #[test]
+    fn parse_event_log_collects_aqe_metric_updates() {
+        let (event_log, _) = parse_fixture("aqe-updates", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveSQLMetricUpdates","executionId":0,"sqlPlanMetrics":[{"name":"number of partitions","accumulatorId":7,"metricType":"sum","value":12},{"name":"data size","accumulatorId":8,"metricType":"size"}]}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.sql_executions[&0].aqe_metric_updates, vec![("number of partitions".to_string(), 12)]);
+    }
+
+    #[test]
+    fn parse_event_log_marks_failed_sql_executions() {
+        let (event_log, _) = parse_fixture("sql-end", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"ok","time":1733052001000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":1,"description":"bad","time":1733052001000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionEnd","executionId":0,"time":1733052002000}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLExecutionEnd","executionId":1,"time":1733052003000,"errorMessage":"Table not found"}"#,
+        ])
+        .unwrap();
+
+        let ok = &event_log.sql_executions[&0];
+        assert!(matches!(ok.status, SqlExecutionStatus::Completed));
+        assert_eq!(ok.completion_time.unwrap().timestamp_millis(), 1733052002000);
+
+        let bad = &event_log.sql_executions[&1];
+        assert!(matches!(bad.status, SqlExecutionStatus::Failed));
+        assert_eq!(bad.error_message.as_deref(), Some("Table not found"));
+    }
+
+    #[test]
+    fn parse_event_log_fails_sql_executions_whose_jobs_failed() {
+        let (event_log, _) = parse_fixture("sql-failed-job", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001100,"Stage Infos":[],"Properties":{"spark.sql.execution.id":"0"}}"#,
+            r#"{"Event":"SparkListenerJobEnd","Job ID":0,"Completion Time":1733052001900,"Job Result":{"Result":"JobFailed","Exception":{"Message":"Job aborted due to stage failure"}}}"#,
+            r#"{"Event":"SparkListenerSQLExecutionEnd","executionId":0,"time":1733052002000}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert!(matches!(execution.status, SqlExecutionStatus::Failed));
+        assert_eq!(execution.error_message.as_deref(), Some("Job aborted due to stage failure"));
+    }
+
+    #[test]
+    fn parse_event_log_counts_aqe_coalesced_partitions() {
+        let reader = |specs: usize| json!({
+            "nodeName": "AQEShuffleRead coalesced",
+            "metrics": [],
+            "partitionSpecs": vec![json!({}); specs],
+            "children": [],
+        });
+        let update = json!({
+            "Event": "org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveExecutionUpdate",
+            "executionId": 0,
+            "physicalPlanDescription": "replanned",
+            "sparkPlanInfo": {"nodeName": "SortMergeJoin", "metrics": [], "children": [reader(8), reader(4)]},
+        })
+        .to_string();
+        let (event_log, _) = parse_fixture("aqe-coalesced", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":1,"description":"q","time":1733052001000,"modifiedConfigs":{"spark.sql.shuffle.partitions":"400"}}"#,
+            &update,
+            &update.replace(r#""executionId":0"#, r#""executionId":1"#),
+        ])
+        .unwrap();
+
+        let default = &event_log.sql_executions[&0];
+        assert_eq!(default.aqe_coalesced_partitions, Some(12));
+        assert!(!default.has_skew_join);
+        assert_eq!(default.aqe_initial_partitions, Some(200));
+        assert_eq!(event_log.sql_executions[&1].aqe_initial_partitions, Some(400));
+    }
+
+    #[test]
+    fn parse_event_log_counts_broadcast_joins_added_by_aqe() {
+        let (event_log, _) = parse_fixture("aqe-broadcast", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000,"sparkPlanInfo":{"nodeName":"AdaptiveSparkPlan","metrics":[],"children":[{"nodeName":"BroadcastHashJoin","metrics":[],"children":[{"nodeName":"SortMergeJoin","metrics":[],"children":[]}]}]}}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveExecutionUpdate","executionId":0,"physicalPlanDescription":"replanned","sparkPlanInfo":{"nodeName":"AdaptiveSparkPlan","metrics":[],"children":[{"nodeName":"BroadcastHashJoin","metrics":[],"children":[{"nodeName":"BroadcastHashJoin","metrics":[],"children":[]}]}]}}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.sql_executions[&0].aqe_broadcast_joins_added, 1);
+    }
+
+    #[test]
+    fn plan_has_skew_join_finds_skewed_shuffle_reads() {
+        let plan = |reader: serde_json::Value| json!({
+            "nodeName": "SortMergeJoin",
+            "metrics": [],
+            "children": [{"nodeName": "Sort", "metrics": [], "children": [reader]}],
+        });
+        assert!(plan_has_skew_join(&plan(json!({"nodeName": "AQEShuffleRead", "isSkew": true}))));
+        assert!(plan_has_skew_join(&plan(json!({"nodeName": "CustomShuffleReader coalesced and skewed"}))));
+        assert!(plan_has_skew_join(&plan(json!({
+            "nodeName": "AQEShuffleRead",
+            "metrics": [{"name": "number of skewed partitions", "accumulatorId": 3}],
+        }))));
+        assert!(!plan_has_skew_join(&plan(json!({"nodeName": "AQEShuffleRead coalesced", "metrics": []}))));
+    }
+
+    #[test]
+    fn parse_event_log_settles_sql_executions_without_end_event() {
+        let start = r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#;
+        let (running, _) = parse_fixture("sql-running", &[APPLICATION_START, start]).unwrap();
+        assert!(matches!(running.sql_executions[&0].status, SqlExecutionStatus::Running));
+        assert!(running.sql_executions[&0].completion_time.is_none());
+
+        let (failed, _) = parse_fixture("sql-app-failed", &[
+            APPLICATION_START,
+            start,
+            r#"{"Event":"SparkListenerApplicationEnd","Timestamp":1733052060000,"Exit Code":1}"#,
+        ])
+        .unwrap();
+        let execution = &failed.sql_executions[&0];
+        assert!(matches!(execution.status, SqlExecutionStatus::Failed));
+        assert_eq!(execution.completion_time.unwrap().timestamp_millis(), 1733052060000);
+
+        let (succeeded, _) = parse_fixture("sql-app-succeeded", &[
+            APPLICATION_START,
+            start,
+            r#"{"Event":"SparkListenerApplicationEnd","Timestamp":1733052060000}"#,
+        ])
+        .unwrap();
+        assert!(matches!(succeeded.sql_executions[&0].status, SqlExecutionStatus::Completed));
+    }
+
+    #[test]
+    fn parse_event_log_links_stages_by_plan_input_rdds() {
+        let (event_log, _) = parse_fixture("sql-input-rdds", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":4,"description":"q","time":1733052001000,"sparkPlanInfo":{"nodeName":"Project","metrics":[],"children":[{"nodeName":"Scan parquet","metrics":[],"inputRDDs":[7],"children":[]}]}}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"a","Number of Tasks":1,"RDD Info":[{"RDD ID":8,"Name":"map"},{"RDD ID":7,"Name":"scan"}]}}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":1,"Stage Attempt ID":0,"Stage Name":"b","Number of Tasks":1,"RDD Info":[{"RDD ID":9,"Name":"other"}]}}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.sql_executions[&4].input_rdd_ids, vec![7]);
+        assert_eq!(event_log.sql_executions[&4].stages, vec![0]);
+        assert_eq!(event_log.stages.values().find(|s| s.stage_id == 0).unwrap().sql_execution_id, Some(4));
+        assert_eq!(event_log.stages.values().find(|s| s.stage_id == 1).unwrap().sql_execution_id, None);
+    }
+
+    #[test]
+    fn parse_event_log_links_stages_added_after_aqe_update() {
+        let (event_log, _) = parse_fixture("aqe-stages", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000,"physicalPlanDescription":"initial"}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"a","Number of Tasks":1},"Properties":{"spark.sql.execution.id":"0"}}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveExecutionUpdate","executionId":0,"physicalPlanDescription":"replanned","sparkPlanInfo":{"nodeName":"AdaptiveSparkPlan","metrics":[{"name":"number of partitions","accumulatorId":9}],"children":[]}}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":2,"Stage Attempt ID":0,"Stage Name":"b","Number of Tasks":1},"Properties":{"spark.sql.execution.id":"0"}}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert_eq!(execution.stages, vec![0, 2]);
+        assert_eq!(execution.physical_plan, "replanned");
+        assert_eq!(execution.initial_physical_plan, "initial");
+        assert_eq!(execution.metric_names[&9], "number of partitions");
+    }
+
+    #[test]
+    fn parse_event_log_warns_about_out_of_order_sql_executions() {
+        let (event_log, _) = parse_fixture("sql-order", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":3,"description":"q","time":1733052005000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":1,"description":"q","time":1733052002000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":4,"description":"q","time":1733052006000}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.ordering_warnings, vec!["SQL execution 1 started after SQL execution 3"]);
+        let warnings: Vec<_> = event_log.parse_warnings.iter().map(|w| (w.line_number, w.error.as_str())).collect();
+        assert_eq!(warnings, vec![(3, "SQL execution 1 start time is before the previous event; clamped")]);
+        assert_eq!(event_log.sql_executions[&1].submission_time, event_log.sql_executions[&3].submission_time);
+    }
+
+    #[test]
+    fn parse_event_log_keeps_times_of_concurrent_sql_executions() {
+        // Spark allocates the ID before posting the start, so a concurrent
+        // query can start with a lower ID a moment later
+        let (event_log, _) = parse_fixture("sql-concurrent", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":3,"description":"q","time":1733052005000}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":2,"description":"q","time":1733052005400}"#,
+        ])
+        .unwrap();
+
+        assert!(event_log.parse_warnings.is_empty());
+        assert_eq!(event_log.ordering_warnings, vec!["SQL execution 2 started after SQL execution 3"]);
+        let gap = event_log.sql_executions[&2].submission_time - event_log.sql_executions[&3].submission_time;
+        assert_eq!(gap.num_milliseconds(), 400);
+    }
+
+    #[test]
+    fn parse_event_log_links_jobs_to_sql_executions() {
+        let (event_log, _) = parse_fixture("sql-jobs", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":3,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052001100,"Stage Infos":[],"Properties":{"spark.sql.execution.id":"3"}}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001200,"Stage Infos":[],"Properties":{"spark.sql.execution.id":"3"}}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":2,"Submission Time":1733052001300,"Stage Infos":[]}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.jobs[&1].sql_execution_id, Some(3));
+        assert_eq!(event_log.jobs[&2].sql_execution_id, None);
+        assert_eq!(event_log.sql_executions[&3].jobs, vec![0, 1]);
+        assert_eq!(event_log.sql_execution_for_job(0).map(|e| e.execution_id), Some(3));
+        assert!(event_log.sql_execution_for_job(2).is_none());
+    }
+
+    #[test]
+    fn parse_event_log_fills_sql_metric_values_from_definitions() {
+        let (event_log, _) = parse_fixture("sql-metric-definitions", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000,"metrics":[{"name":"number of output rows","accumulatorId":42,"metricType":"sum"}],"sparkPlanInfo":{"nodeName":"BroadcastExchange","metrics":[{"name":"data size","accumulatorId":43,"metricType":"size"},{"name":"time to broadcast","accumulatorId":44,"metricType":"timing"},{"name":"never updated","accumulatorId":45,"metricType":"sum"}],"children":[]}}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"scan","Number of Tasks":1}}"#,
+            r#"{"Event":"SparkListenerStageCompleted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"scan","Number of Tasks":1,"Accumulables":[{"ID":42,"Name":"number of output rows","Value":"1000"},{"ID":43,"Name":"data size","Value":2048}]}}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerDriverAccumUpdates","executionId":0,"accumUpdates":[[44,120]]}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert_eq!(execution.metric_definitions.len(), 4);
+        assert_eq!(execution.metric_definitions[1], SqlMetricDefinition {
+            accumulator_id: 43,
+            name: "data size".to_string(),
+            metric_type: "size".to_string(),
+        });
+        let values: Vec<_> = execution.metrics.iter().map(|m| (m.name.as_str(), m.value, m.metric_type.as_str())).collect();
+        assert_eq!(values, vec![
+            ("number of output rows", 1000, "sum"),
+            ("data size", 2048, "size"),
+            ("time to broadcast", 120, "timing"),
+        ]);
+    }
+
+    #[test]
+    fn parse_event_log_values_declared_aqe_metrics_from_accumulators() {
+        let (event_log, _) = parse_fixture("aqe-declared-metrics", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000}"#,
+            r#"{"Event":"org.apache.spark.sql.execution.ui.SparkListenerSQLAdaptiveSQLMetricUpdates","executionId":0,"sqlPlanMetrics":[{"name":"number of partitions","accumulatorId":7,"metricType":"sum"},{"name":"never updated","accumulatorId":8,"metricType":"sum"}]}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"read","Number of Tasks":1}}"#,
+            r#"{"Event":"SparkListenerStageCompleted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"read","Number of Tasks":1,"Accumulables":[{"ID":7,"Name":"number of partitions","Value":"12"}]}}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert_eq!(execution.aqe_metric_updates, vec![("number of partitions".to_string(), 12)]);
+        assert_eq!(execution.metric_names[&8], "never updated");
+    }
+
+    #[test]
+    fn parse_event_log_names_aqe_updates_from_plan_metrics() {
+        let (event_log, _) = parse_fixture("plan-metrics", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052001000,"sparkPlanInfo":{"nodeName":"Project","metrics":[],"children":[{"nodeName":"Scan parquet","metrics":[{"name":"number of output rows","accumulatorId":42,"metricType":"sum"}],"children":[]}]}}"#,
+            r#"{"Event":"SparkListenerSQLAdaptiveSQLMetricUpdates","executionId":0,"sqlPlanMetrics":[{"accumulatorId":42,"value":1000}]}"#,
+        ])
+        .unwrap();
+
+        let execution = &event_log.sql_executions[&0];
+        assert_eq!(execution.metric_names[&42], "number of output rows");
+        assert_eq!(execution.aqe_metric_updates, vec![("number of output rows".to_string(), 1000)]);
+    }
+
+    #[test]
+    fn parse_job_start_reads_id_time_and_stages() {
+        let event = json!({
+            "Event": "SparkListenerJobStart",
+            "Job ID": 7,
+            "Submission Time": 1733052001000u64,
+            "Stage IDs": [3, 4]
+        });
+
+        let job = parse_job_start(&event).unwrap();
+        assert_eq!(job.job_id, 7);
+        assert_eq!(job.name, "Job 7");
+        assert_eq!(job.submission_time.timestamp_millis(), 1733052001000);
+        assert_eq!(job.stage_ids, vec![3, 4]);
+        assert_eq!(job.description, None);
+        assert!(matches!(job.status, JobStatus::Running));
+        assert!(parse_job_start(&json!({"Event": "SparkListenerJobStart"})).is_err());
+    }
+
+    #[test]
+    fn parse_event_log_counts_job_tasks_from_stage_infos() {
+        let (event_log, _) = parse_fixture("job-tasks", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage Infos":[{"Stage ID":0,"Number of Tasks":4},{"Stage ID":1,"Number of Tasks":200}]}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":1,"Stage Attempt ID":0,"Stage Name":"b","Number of Tasks":12}}"#,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":1,"Stage Attempt ID":1,"Stage Name":"b","Number of Tasks":3}}"#,
+        ])
+        .unwrap();
+
+        let job = &event_log.jobs[&0];
+        assert_eq!(job.stage_ids, vec![0, 1]);
+        assert_eq!(job.num_tasks, 16);
+    }
+
+    #[test]
+    fn parse_job_start_uses_job_description_property() {
+        let event = json!({
+            "Event": "SparkListenerJobStart",
+            "Job ID": 1,
+            "Stage IDs": [],
+            "Properties": {
+                "spark.job.description": "select * from events",
+                "callSite.long": "org.apache.spark.sql.Dataset.collect(Dataset.scala:3000)"
+            }
+        });
+
+        let job = parse_job_start(&event).unwrap();
+        assert_eq!(job.description.as_deref(), Some("select * from events"));
+        assert_eq!(job.properties.len(), 2);
+    }
+
+    #[test]
+    fn parse_event_log_marks_stages_failed_in_job_start() {
+        let (event_log, _) = parse_fixture("job-start-failed-stage", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"scan","Number of Tasks":4}}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052005000,"Stage Infos":[{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"scan","Number of Tasks":4,"Failure Reason":"FetchFailed(BlockManagerId(2, host-b))\n\tat org.apache.spark..."},{"Stage ID":1,"Stage Attempt ID":1,"Stage Name":"agg","Number of Tasks":2,"Completion Time":1733052004000,"Failure Reason":"Job aborted"},{"Stage ID":2,"Stage Attempt ID":0,"Stage Name":"write","Number of Tasks":2}],"Stage IDs":[0,1,2]}"#,
+        ])
+        .unwrap();
+
+        let retried = &event_log.stages[&(0, 0)];
+        assert!(matches!(retried.status, StageStatus::Failed));
+        assert!(retried.failure_reason.as_deref().unwrap().starts_with("FetchFailed"));
+
+        let unseen = &event_log.stages[&(1, 1)];
+        assert!(matches!(unseen.status, StageStatus::Failed));
+        assert_eq!(unseen.failure_reason.as_deref(), Some("Job aborted"));
+        assert_eq!(unseen.completion_time.unwrap().timestamp_millis(), 1733052004000);
+        assert!(!event_log.stages.contains_key(&(2, 0)));
+    }
+
+    #[test]
+    fn parse_event_log_reads_task_binary_size_on_stage_completion() {
+        let (event_log, _) = parse_fixture("task-binary", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"map","Number of Tasks":4}}"#,
+            r#"{"Event":"SparkListenerStageCompleted","Stage Info":{"Stage ID":0,"Stage Attempt ID":0,"Stage Name":"map","Number of Tasks":4,"Completion Time":1733052003000,"Task Binary File Size":2411724}}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.stages[&(0, 0)].task_binary_size, Some(2411724));
+    }
+
+    #[test]
+    fn parse_stage_submitted_reads_sql_execution_id() {
+        let event = json!({
+            "Event": "SparkListenerStageSubmitted",
+            "Stage Info": {"Stage ID": 4, "Stage Attempt ID": 0, "Stage Name": "save", "Number of Tasks": 8},
+            "Properties": {"spark.sql.execution.id": "12", "spark.job.description": "insert"}
+        });
+
+        let stage = parse_stage_submitted(&event).unwrap();
+        assert_eq!(stage.sql_execution_id, Some(12));
+        assert_eq!(stage.properties.len(), 2);
+    }
+
+    #[test]
+    fn parse_stage_submitted_reads_call_site_details() {
+        let event = json!({
+            "Event": "SparkListenerStageSubmitted",
+            "Stage Info": {
+                "Stage ID": 1,
+                "Stage Name": "count at Etl.scala:42",
+                "Number of Tasks": 2,
+                "Details": "org.apache.spark.rdd.RDD.count(RDD.scala:1296)\ncom.example.Etl$.main(Etl.scala:42)"
+            }
+        });
+
+        let stage = parse_stage_submitted(&event).unwrap();
+        assert_eq!(stage.details.lines().nth(1), Some("com.example.Etl$.main(Etl.scala:42)"));
+    }
+
+    #[test]
+    fn parse_job_result_maps_result_names() {
+        let result = |name: &str| json!({"Job Result": {"Result": name}});
+        assert!(matches!(parse_job_result(&result("JobSucceeded")), JobStatus::Succeeded));
+        assert!(matches!(parse_job_result(&result("JobFailed")), JobStatus::Failed));
+        assert!(matches!(parse_job_result(&json!({})), JobStatus::Unknown));
+    }
+
+    #[test]
+    fn parse_job_failure_reason_reads_exception_message() {
+        let failed = json!({
+            "Job Result": {
+                "Result": "JobFailed",
+                "Exception": {"Message": "Job aborted due to stage failure", "Stack Trace": []}
+            }
+        });
+        assert_eq!(parse_job_failure_reason(&failed).as_deref(), Some("Job aborted due to stage failure"));
+        assert_eq!(parse_job_failure_reason(&json!({"Job Result": {"Result": "JobSucceeded"}})), None);
+    }
+
+    #[test]
+    fn parse_task_metrics_reads_task_end_fixture() {
+        let event = json!({
+            "Event": "SparkListenerTaskEnd",
+            "Task Info": {
+                "Task ID": 1,
+                "Launch Time": 1733052000000u64,
+                "Finish Time": 1733052001000u64,
+                "Getting Result Time": 0
+            },
+            "Task Metrics": {
+                "Executor Deserialize Time": 50,
+                "Executor Run Time": 800,
+                "Executor CPU Time": 700000000u64,
+                "Result Size": 2048,
+                "JVM GC Time": 20,
+                "Result Serialization Time": 10,
+                "Memory Bytes Spilled": 100,
+                "Disk Bytes Spilled": 200,
+                "Peak Execution Memory": 4096,
+                "Input Metrics": {"Bytes Read": 1000, "Records Read": 10},
+                "Shuffle Write Metrics": {"Bytes Written": 300, "Records Written": 3, "Write Time": 5}
+            }
+        });
+
+        let metrics = parse_task_metrics(&event).unwrap();
+        assert_eq!(metrics.execution_time, 800);
+        assert_eq!(metrics.deserialization_time, 50);
+        assert_eq!(metrics.cpu_time, 700000000);
+        assert_eq!(metrics.gc_time, 20);
+        assert_eq!(metrics.result_size, 2048);
+        assert_eq!(metrics.result_serialization_time, 10);
+        assert_eq!(metrics.scheduler_delay, 1000 - 800 - 50 - 10);
+        assert_eq!(metrics.memory_bytes_spilled, 100);
+        assert_eq!(metrics.disk_bytes_spilled, 200);
+        assert_eq!(metrics.peak_execution_memory, 4096);
+        assert_eq!(metrics.input_metrics.unwrap().bytes_read, 1000);
+        assert_eq!(metrics.shuffle_write_metrics.unwrap().bytes_written, 300);
+        assert!(metrics.output_metrics.is_none());
+    }
+
+    #[test]
+    fn parse_task_metrics_falls_back_to_internal_accumulators() {
+        let event = json!({
+            "Event": "SparkListenerTaskEnd",
+            "Task Info": {
+                "Task ID": 2,
+                "Accumulables": [
+                    {"ID": 1, "Name": "internal.metrics.shuffle.write.recordsWritten", "Update": 42, "Value": 100},
+                    {"ID": 2, "Name": "internal.metrics.shuffle.write.bytesWritten", "Update": 999, "Value": 5000},
+                    {"ID": 3, "Name": "internal.metrics.output.recordsWritten", "Update": "7", "Value": "7"},
+                    {"ID": 4, "Name": "number of output rows", "Update": 5, "Value": 5}
+                ]
+            },
+            "Task Metrics": {
+                "Executor Run Time": 800,
+                "Shuffle Write Metrics": {"Bytes Written": 300, "Write Time": 5}
+            }
+        });
+
+        let metrics = parse_task_metrics(&event).unwrap();
+        let shuffle_write = metrics.shuffle_write_metrics.unwrap();
+        assert_eq!(shuffle_write.records_written, 42);
+        assert_eq!(shuffle_write.bytes_written, 300);
+        assert_eq!(metrics.output_metrics.unwrap().records_written, 7);
+        assert!(metrics.input_metrics.is_none());
+    }
+
+    #[test]
+    fn parse_task_metrics_reads_push_based_shuffle_counters() {
+        let event = json!({
+            "Event": "SparkListenerTaskEnd",
+            "Task Metrics": {
+                "Executor Run Time": 800,
+                "Shuffle Read Metrics": {
+                    "Remote Blocks Fetched": 10,
+                    "Local Blocks Fetched": 2,
+                    "Remote Bytes Read": 800,
+                    "Local Bytes Read": 200,
+                    "Push Based Shuffle": {
+                        "Merged Remote Blocks Fetched": 3,
+                        "Merged Local Blocks Fetched": 1,
+                        "Merged Remote Bytes Read": 700,
+                        "Merged Local Bytes Read": 173
+                    }
+                }
+            }
+        });
+
+        let shuffle_read = parse_task_metrics(&event).unwrap().shuffle_read_metrics.unwrap();
+        assert!(shuffle_read.push_based_shuffle);
+        assert_eq!((shuffle_read.remote_merged_blocks_fetched, shuffle_read.local_merged_blocks_fetched), (3, 1));
+        assert_eq!(format!("{:.1}", shuffle_read.push_merge_ratio().unwrap()), "87.3");
+
+        let plain = json!({"Task Metrics": {"Shuffle Read Metrics": {"Remote Bytes Read": 800}}});
+        let shuffle_read = parse_task_metrics(&plain).unwrap().shuffle_read_metrics.unwrap();
+        assert!(!shuffle_read.push_based_shuffle);
+        assert_eq!(shuffle_read.push_merge_ratio(), None);
+    }
+
+    #[test]
+    fn parse_task_metrics_ignores_zero_filled_push_based_shuffle() {
+        let event = json!({
+            "Task Metrics": {
+                "Shuffle Read Metrics": {
+                    "Remote Bytes Read": 800,
+                    "Local Bytes Read": 200,
+                    "Push Based Shuffle": {
+                        "Corrupt Merged Block Chunks": 0,
+                        "Merged Fetch Fallback Count": 0,
+                        "Merged Remote Blocks Fetched": 0,
+                        "Merged Local Blocks Fetched": 0,
+                        "Merged Remote Chunks Fetched": 0,
+                        "Merged Local Chunks Fetched": 0,
+                        "Merged Remote Bytes Read": 0,
+                        "Merged Local Bytes Read": 0,
+                        "Merged Remote Requests Duration": 0
+                    }
+                }
+            }
+        });
+
+        let shuffle_read = parse_task_metrics(&event).unwrap().shuffle_read_metrics.unwrap();
+        assert!(!shuffle_read.push_based_shuffle);
+        assert_eq!(shuffle_read.push_merge_ratio(), None);
+    }
+
+    #[test]
+    fn parse_event_log_with_progress_reports_every_interval() {
+        let path = std::env::temp_dir().join(format!("spark-tui-progress-{}.json", std::process::id()));
+        let unknown = r#"{"Event":"com.example.CustomListenerEvent"}"#;
+        let mut lines = vec![APPLICATION_START];
+        lines.extend(std::iter::repeat_n(unknown, 25_000));
+        std::fs::write(&path, lines.join("\n")).unwrap();
+
+        let mut calls = Vec::new();
+        let result = parse_event_log_with_progress(&path, &ParseOptions::default(), |bytes_read, total_bytes| calls.push((bytes_read, total_bytes)));
+        std::fs::remove_file(&path).unwrap();
+
+        let (_, metadata) = result.unwrap();
+        assert_eq!(calls.len(), 3);
+        assert!(calls[0].0 < calls[1].0);
+        assert_eq!(calls[2], (metadata.file_size_bytes, metadata.file_size_bytes));
+    }
+
+    #[test]
+    fn parse_rolling_event_log_warns_about_missing_parts() {
+        let dir = std::env::temp_dir().join(format!("spark-tui-rolling-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("app.log"), APPLICATION_START).unwrap();
+        std::fs::write(dir.join("app.log.1"), r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0]}"#).unwrap();
+        std::fs::write(dir.join("app.log.3"), r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052002000,"Stage IDs":[1]}"#).unwrap();
+
+        let options = ParseOptions { rolling: true, ..ParseOptions::default() };
+        let mut calls = 0;
+        let result = parse_event_log_with_progress(&dir.join("app.log.1"), &options, |_, _| calls += 1);
+        let size = event_log_size(&dir.join("app.log"), &options).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        let (event_log, metadata) = result.unwrap();
+        assert_eq!(event_log.jobs.len(), 2);
+        assert_eq!((metadata.line_count, metadata.file_size_bytes), (3, size));
+        assert_eq!(calls, 1);
+        assert_eq!(event_log.parse_warnings.len(), 1);
+        assert_eq!(event_log.parse_warnings[0].line_number, 3);
+        assert_eq!(event_log.parse_warnings[0].error, "Rolling event log app.log is missing part 2, continuing");
+    }
+
+    #[test]
+    fn scan_log_dir_summarizes_without_full_parse() {
+        let dir = std::env::temp_dir().join(format!("spark-tui-scan-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("app-1"), [
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0]}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Task Info":{"Task ID":0}}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052002000,"Stage IDs":[1]}"#,
+            r#"{"Event":"SparkListenerApplicationEnd","Timestamp":1733052045200}"#,
+        ].join("\n")).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not an event log").unwrap();
+
+        let since = DateTime::from_timestamp_millis(1733052001500);
+        let entries = scan_log_dir(&dir, false, &ParseOptions { since, ..ParseOptions::default() }).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].result, Ok(LogFileSummary {
+            app_name: "Fixture App".to_string(),
+            app_id: "app-1".to_string(),
+            job_count: 1,
+            duration_ms: Some(45_200),
+        }));
+        assert_eq!(entries[1].result, Err("No application start event found in event log".to_string()));
+    }
+
+    #[test]
+    fn parse_event_log_keeps_parse_warnings_with_their_attempt() {
+        let (event_log, _) = parse_fixture("attempt-warnings", &[
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"1","Timestamp":1733052000000,"User":"test"}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0"#,
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"2","Timestamp":1733052010000,"User":"test"}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"q","time":1733052011000}"#,
+            r#"{"Event":"SparkListenerJobStart""#,
+        ])
+        .unwrap();
+
+        let lines = |log: &SparkEventLog| log.parse_warnings.iter().map(|w| w.line_number).collect::<Vec<_>>();
+        assert_eq!(lines(&event_log.previous_attempts[0]), vec![2]);
+        assert_eq!(lines(&event_log), vec![5]);
+    }
+
+    #[test]
+    fn parse_event_log_splits_application_attempts() {
+        let (event_log, _) = parse_fixture("attempts", &[
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"1","Timestamp":1733052000000,"User":"test"}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0]}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052002000,"Stage IDs":[1]}"#,
+            r#"{"Event":"SparkListenerApplicationEnd","Timestamp":1733052003000,"Exit Code":1}"#,
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"2","Timestamp":1733052010000,"User":"test"}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052011000,"Stage IDs":[0]}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.application_info.app_attempt_id.as_deref(), Some("2"));
+        assert_eq!(event_log.jobs.len(), 1);
+        assert_eq!(event_log.jobs[&0].submission_time.timestamp_millis(), 1733052011000);
+        assert_eq!(event_log.application_attempts.len(), 2);
+
+        let first = &event_log.previous_attempts[0];
+        assert_eq!(first.application_info.app_attempt_id.as_deref(), Some("1"));
+        assert_eq!(first.application_info.exit_code, Some(1));
+        assert_eq!(first.jobs.len(), 2);
+        assert_eq!(first.application_attempts.len(), 2);
+    }
+
+    #[test]
+    fn parse_event_log_keeps_preamble_events_with_their_attempt() {
+        let (event_log, _) = parse_fixture("attempt-preamble", &[
+            r#"{"Event":"SparkListenerLogStart","Spark Version":"3.5.0"}"#,
+            r#"{"Event":"SparkListenerResourceProfileAdded","Resource Profile Id":0,"Executor Resource Requests":{},"Task Resource Requests":{}}"#,
+            r#"{"Event":"SparkListenerBlockManagerAdded","Block Manager ID":{"Executor ID":"driver","Host":"first","Port":7078},"Maximum Memory":1024}"#,
+            r#"{"Event":"SparkListenerEnvironmentUpdate","Spark Properties":{"spark.app.attempt":"1"}}"#,
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"1","Timestamp":1733052000000,"User":"test"}"#,
+            r#"{"Event":"SparkListenerLogStart","Spark Version":"3.5.0"}"#,
+            r#"{"Event":"SparkListenerResourceProfileAdded","Resource Profile Id":0,"Executor Resource Requests":{},"Task Resource Requests":{}}"#,
+            r#"{"Event":"SparkListenerBlockManagerAdded","Block Manager ID":{"Executor ID":"driver","Host":"second","Port":7078},"Maximum Memory":2048}"#,
+            r#"{"Event":"SparkListenerEnvironmentUpdate","Spark Properties":{"spark.app.attempt":"2"}}"#,
+            r#"{"Event":"SparkListenerApplicationStart","App Name":"Retried","App ID":"application_1_0001","App Attempt ID":"2","Timestamp":1733052010000,"User":"test"}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.application_info.app_attempt_id.as_deref(), Some("2"));
+        assert_eq!(event_log.environment.spark_properties["spark.app.attempt"], "2");
+        assert_eq!(event_log.driver_block_manager.as_ref().unwrap().host, "second");
+        assert!(event_log.resource_profiles.contains_key(&0));
+
+        let first = &event_log.previous_attempts[0];
+        assert_eq!(first.environment.spark_properties["spark.app.attempt"], "1");
+        assert_eq!(first.driver_block_manager.as_ref().unwrap().host, "first");
+        assert!(first.resource_profiles.contains_key(&0));
+    }
+
+    #[test]
+    fn parse_event_log_reads_application_exit_code() {
+        let (event_log, _) = parse_fixture("exit-code", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerApplicationEnd","Timestamp":1733052060000,"Exit Code":1}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.application_info.exit_code, Some(1));
+    }
+
+    #[test]
+    fn parse_event_log_drops_jobs_and_tasks_before_since() {
+        let options = ParseOptions { since: DateTime::from_timestamp_millis(1733052003000), ..ParseOptions::default() };
+        let (event_log, _) = parse_fixture_with_options("since", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0]}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052005000,"Stage IDs":[1]}"#,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":1,"Stage Attempt ID":0,"Task Info":{"Task ID":7,"Launch Time":1733052005100,"Executor ID":"1","Host":"h"}}"#,
+        ], &options)
+        .unwrap();
+
+        assert_eq!(event_log.jobs.keys().collect::<Vec<_>>(), vec![&1]);
+        assert_eq!(event_log.tasks.len(), 1);
+    }
+
+    #[test]
+    fn parse_event_log_recounts_executor_and_sql_totals_after_since() {
+        let options = ParseOptions { since: DateTime::from_timestamp_millis(1733052003000), ..ParseOptions::default() };
+        let (event_log, _) = parse_fixture_with_options("since-totals", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerExecutorAdded","Executor ID":"1","Executor Info":{"Host":"h","Total Cores":4}}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":0,"description":"old","time":1733052000500}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0],"Properties":{"spark.sql.execution.id":"0"}}"#,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":0,"Stage Attempt ID":0,"Task Info":{"Task ID":0,"Launch Time":1733052001100,"Executor ID":"1","Host":"h"}}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Stage ID":0,"Stage Attempt ID":0,"Task Info":{"Task ID":0,"Finish Time":1733052002000},"Task Metrics":{"Executor Run Time":900,"JVM GC Time":300}}"#,
+            r#"{"Event":"SparkListenerSQLExecutionStart","executionId":1,"description":"new","time":1733052004000}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":1,"Submission Time":1733052004100,"Stage IDs":[1],"Properties":{"spark.sql.execution.id":"1"}}"#,
+            r#"{"Event":"SparkListenerJobStart","Job ID":2,"Submission Time":1733052001200,"Stage IDs":[2],"Properties":{"spark.sql.execution.id":"1"}}"#,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":1,"Stage Attempt ID":0,"Task Info":{"Task ID":1,"Launch Time":1733052004200,"Executor ID":"1","Host":"h"}}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Stage ID":1,"Stage Attempt ID":0,"Task Info":{"Task ID":1,"Finish Time":1733052005000},"Task Metrics":{"Executor Run Time":400,"JVM GC Time":20}}"#,
+        ], &options)
+        .unwrap();
+
+        let executor = &event_log.executors["1"];
+        assert_eq!((executor.total_duration, executor.total_gc_time), (400, 20));
+        assert_eq!(event_log.sql_executions.len(), 1);
+        assert_eq!(event_log.sql_executions[&1].jobs, vec![1]);
+    }
+
+    #[test]
+    fn parse_event_log_ignores_unknown_event_types() {
+        let (event_log, _) = parse_fixture("unknown-event", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerUnpersistRDD","RDD ID":3}"#,
+            r#"{"Event":"com.example.CustomListenerEvent","payload":42}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.application_info.app_name, "Fixture App");
+        assert!(event_log.parse_warnings.is_empty());
+    }
+
+    #[test]
+    fn parse_event_log_reads_block_manager_memory_limits() {
+        let (event_log, _) = parse_fixture("block-manager-added", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerBlockManagerAdded","Block Manager ID":{"Executor ID":"driver","Host":"h","Port":7078},"Maximum Memory":1024}"#,
+            r#"{"Event":"SparkListenerExecutorAdded","Executor ID":"1","Executor Info":{"Host":"h","Total Cores":4}}"#,
+            r#"{"Event":"SparkListenerBlockManagerAdded","Block Manager ID":{"Executor ID":"1","Host":"h","Port":7079},"Maximum Memory":4294967296,"Maximum Onheap Memory":4294967296,"Maximum Offheap Memory":0}"#,
+            r#"{"Event":"SparkListenerExecutorAdded","Executor ID":"2","Executor Info":{"Host":"h","Total Cores":4}}"#,
+            r#"{"Event":"SparkListenerBlockManagerAdded","Block Manager ID":{"Executor ID":"2"},"Executor Info":{"Max On Heap Memory":2048,"Max Off Heap Memory":512}}"#,
+        ])
+        .unwrap();
+
+        let executor = &event_log.executors["1"];
+        assert_eq!(executor.max_on_heap_memory, 4294967296);
+        assert_eq!(executor.max_off_heap_memory, 0);
+        let executor = &event_log.executors["2"];
+        assert_eq!((executor.max_on_heap_memory, executor.max_off_heap_memory), (2048, 512));
+        assert!(!event_log.executors.contains_key("driver"));
+        let driver = event_log.driver_block_manager.as_ref().unwrap();
+        assert_eq!((driver.host.as_str(), driver.port), ("h", 7078));
+        // No on/off-heap split reported, so it is all on-heap
+        assert_eq!((driver.max_on_heap_memory, driver.max_off_heap_memory), (1024, 0));
+    }
+
+    #[test]
+    fn parse_executor_added_reads_custom_resources() {
+        let event = json!({
+            "Event": "SparkListenerExecutorAdded",
+            "Executor ID": "1",
+            "Executor Info": {
+                "Host": "gpu-node-1",
+                "Total Cores": 8,
+                "Resources": {
+                    "gpu": {"name": "gpu", "addresses": ["0", "1"]},
+                    "fpga": {"name": "fpga", "amount": 1, "addresses": []}
+                }
+            }
+        });
+
+        let executor = parse_executor_added(&event).unwrap();
+        assert_eq!(executor.resources["gpu"], ResourceAmount { amount: 2, addresses: vec!["0".to_string(), "1".to_string()] });
+        assert_eq!(executor.resources["fpga"].amount, 1);
+    }
+
+    #[test]
+    fn parse_executor_added_keeps_kubernetes_attributes() {
+        let event = json!({
+            "Event": "SparkListenerExecutorAdded",
+            "Executor ID": "3",
+            "Executor Info": {
+                "Host": "10.0.0.7",
+                "Total Cores": 4,
+                "Attributes": {
+                    "kubernetes.pod.name": "etl-exec-3",
+                    "kubernetes.node.name": "node-a",
+                    "LOG_FILES": "stderr,stdout"
+                }
+            }
+        });
+
+        let executor = parse_executor_added(&event).unwrap();
+        assert_eq!(executor.port, 0);
+        assert_eq!(executor.k8s_attributes.len(), 2);
+        assert_eq!(executor.k8s_attributes["kubernetes.pod.name"], "etl-exec-3");
+        assert_eq!(executor.attributes.len(), 3);
+        assert_eq!(executor.attributes["LOG_FILES"], "stderr,stdout");
+    }
+
+    #[test]
+    fn parse_executor_added_reads_port_from_either_location() {
+        let nested = json!({
+            "Event": "SparkListenerExecutorAdded",
+            "Executor ID": "1",
+            "Executor Info": {"Host": "worker1", "Port": 43211, "Total Cores": 4}
+        });
+        assert_eq!(parse_executor_added(&nested).unwrap().port, 43211);
+
+        let top_level = json!({
+            "Event": "SparkListenerExecutorAdded",
+            "Executor ID": "2",
+            "Port": 43212,
+            "Executor Info": {"Host": "worker2", "Total Cores": 4}
+        });
+        assert_eq!(parse_executor_added(&top_level).unwrap().port, 43212);
+    }
+
+    #[test]
+    fn parse_event_log_collects_block_manager_migrations() {
+        let (event_log, _) = parse_fixture("bm-migrations", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerBlockManagerGainedOwnership","Source Executor ID":"4","Timestamp":1733052030000,"Number of Blocks":12}"#,
+        ])
+        .unwrap();
+
+        let migration = &event_log.block_manager_migrations[0];
+        assert_eq!(migration.source_executor_id, "4");
+        assert_eq!(migration.target_executor_id, "driver");
+        assert_eq!(migration.num_blocks, 12);
+    }
+
+    #[test]
+    fn parse_event_log_tracks_excluded_nodes_under_both_names() {
+        let (event_log, _) = parse_fixture("excluded-nodes", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerNodeBlacklisted","time":1733052010000,"hostId":"node1","executorFailures":2}"#,
+            r#"{"Event":"SparkListenerNodeExcluded","time":1733052020000,"hostId":"node2","executorFailures":3}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.excluded_nodes.len(), 2);
+        assert_eq!(event_log.excluded_nodes[0].0, "node1");
+        assert_eq!(event_log.excluded_nodes[1].1.timestamp_millis(), 1733052020000);
+    }
+
+    #[test]
+    fn parse_event_log_collects_unschedulable_task_sets() {
+        let (event_log, _) = parse_fixture("unschedulable", &[
+            APPLICATION_START,
+            r#"{"Event":"org.apache.spark.scheduler.SparkListenerUnschedulableTaskSetAdded","stageId":4,"stageAttemptId":1}"#,
+            r#"{"Event":"SparkListenerUnschedulableTaskSetAdded","stageId":5,"time":1733052030000}"#,
+        ])
+        .unwrap();
+
+        let events = &event_log.unschedulable_events;
+        assert_eq!(events.len(), 2);
+        assert_eq!((events[0].stage_id, events[0].stage_attempt_id), (4, 1));
+        assert!(events[0].timestamp.is_none());
+        assert_eq!(events[1].timestamp.unwrap().timestamp_millis(), 1733052030000);
+    }
+
+    #[test]
+    fn parse_misc_process_splits_host_and_port() {
+        let event = json!({
+            "Event": "SparkListenerMiscellaneousProcessAdded",
+            "time": 1733052000500u64,
+            "processId": "yarn-am",
+            "info": {"hostPort": "am-host:41234", "cores": 1, "totalCores": 1}
+        });
+
+        let process = parse_misc_process(&event).unwrap();
+        assert_eq!(process.name, "yarn-am");
+        assert_eq!(process.host, "am-host");
+        assert_eq!(process.port, 41234);
+    }
+
+    #[test]
+    fn parse_event_log_reads_resource_profiles() {
+        let (event_log, _) = parse_fixture("resource-profiles", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerResourceProfileAdded","Resource Profile Id":1,"Executor Resource Requests":{"cores":{"Resource Name":"cores","Amount":4,"Discovery Script":"","Vendor":""},"memory":{"Resource Name":"memory","Amount":8192,"Discovery Script":"","Vendor":""},"gpu":{"Resource Name":"gpu","Amount":1,"Discovery Script":"/opt/getGpus.sh","Vendor":"nvidia.com"}},"Task Resource Requests":{"cpus":{"Resource Name":"cpus","Amount":1.0},"gpu":{"Resource Name":"gpu","Amount":0.25}}}"#,
+            r#"{"Event":"SparkListenerExecutorAdded","Executor ID":"1","Executor Info":{"Host":"h","Total Cores":4,"Resource Profile Id":1}}"#,
+        ])
+        .unwrap();
+
+        let profile = &event_log.resource_profiles[&1];
+        assert_eq!(profile.executor_resources["memory"], 8192);
+        assert_eq!(profile.executor_resources["gpu"], 1);
+        assert_eq!(profile.task_resources["gpu"], 0.25);
+        assert_eq!(event_log.executors["1"].resource_profile_id, Some(1));
+    }
+
+    #[test]
+    fn parse_event_log_tracks_cluster_nodes() {
+        let (event_log, _) = parse_fixture("cluster-nodes", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerNodeAdded","Host":"node1","Port":7078,"Cores":16}"#,
+            r#"{"Event":"SparkListenerNodeAdded","Host":"node2","Port":7078,"Cores":8}"#,
+            r#"{"Event":"SparkListenerNodeRemoved","Host":"node2"}"#,
+        ])
+        .unwrap();
+
+        assert_eq!(event_log.cluster_nodes.len(), 2);
+        assert_eq!(event_log.cluster_nodes["node1"].cores, 16);
+        assert!(event_log.cluster_nodes["node1"].is_active);
+        assert!(!event_log.cluster_nodes["node2"].is_active);
+    }
+
+    #[test]
+    fn parse_event_log_tracks_workers() {
+        let (event_log, _) = parse_fixture("workers", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerWorkerAdded","Worker Id":"worker-1","Host":"node1","Port":7078,"Cores":8,"Memory":17179869184}"#,
+            r#"{"Event":"SparkListenerWorkerRemoved","Worker Id":"worker-1"}"#,
+        ])
+        .unwrap();
+
+        let worker = &event_log.workers["worker-1"];
+        assert_eq!(worker.host, "node1");
+        assert_eq!(worker.memory, 17_179_869_184);
+        assert!(!worker.is_active);
+    }
+
+    #[test]
+    fn parse_event_log_requires_application_start() {
+        let error = parse_fixture("no-app-start", &[
+            r#"{"Event":"SparkListenerJobStart","Job ID":0,"Submission Time":1733052001000,"Stage IDs":[0]}"#,
+        ])
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "No application start event found in event log");
+    }
+
+    #[test]
+    fn parse_task_start_falls_back_to_event_stage_id() {
+        // Spark 2.x layout: stage fields live on the event, not in Task Info
+        let event = json!({
+            "Event": "SparkListenerTaskStart",
+            "Stage ID": 3,
+            "Stage Attempt ID": 1,
+            "Task Info": {
+                "Task ID": 42,
+                "Index": 5,
+                "Attempt": 0,
+                "Launch Time": 1733052005200u64,
+                "Executor ID": "2",
+                "Host": "worker2",
+                "Locality": "PROCESS_LOCAL",
+                "Speculative": false
+            }
+        });
+
+        let task = parse_task_start(&event).unwrap();
+        assert_eq!(task.task_id, 42);
+        assert_eq!(task.stage_id, 3);
+        assert_eq!(task.stage_attempt_id, 1);
+        assert_eq!(task.task_index, 5);
+    }
+
+    #[test]
+    fn parse_event_log_measures_result_fetch_from_getting_result_event() {
+        let (event_log, _) = parse_fixture("getting-result", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":0,"Stage Attempt ID":0,"Task Info":{"Task ID":7,"Launch Time":1733052001000,"Executor ID":"1","Host":"worker1"}}"#,
+            r#"{"Event":"SparkListenerTaskGettingResult","Task Info":{"Task ID":7,"Launch Time":1733052001000,"Getting Result Time":1733052001766}}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Stage ID":0,"Task Info":{"Task ID":7,"Launch Time":1733052001000,"Finish Time":1733052002000,"Getting Result Time":0,"Finished":true},"Task Metrics":{"Executor Run Time":700}}"#,
+        ])
+        .unwrap();
+
+        let metrics = event_log.tasks[&7].metrics.as_ref().unwrap();
+        assert_eq!(metrics.result_getting_time, 234);
+        assert_eq!(metrics.scheduler_delay, 66);
+    }
+
+    #[test]
+    fn parse_event_log_records_stage_executor_peaks() {
+        let (event_log, _) = parse_fixture("stage-executor-metrics", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerStageSubmitted","Stage Info":{"Stage ID":2,"Stage Attempt ID":0,"Stage Name":"a","Number of Tasks":4}}"#,
+            r#"{"Event":"SparkListenerStageExecutorMetrics","Executor ID":"1","Stage ID":2,"Stage Attempt ID":0,"Executor Metrics":{"JVMHeapMemory":536870912,"JVMOffHeapMemory":104857600,"ProcessTreeJVMRSSMemory":1073741824}}"#,
+            r#"{"Event":"SparkListenerStageExecutorMetrics","Executor ID":"driver","Stage ID":2,"Stage Attempt ID":0,"Executor Metrics":{"JVMHeapMemory":268435456}}"#,
+        ])
+        .unwrap();
+
+        let stage = &event_log.stages[&(2, 0)];
+        assert_eq!(stage.executor_peak_metrics.len(), 2);
+        let peaks = &stage.executor_peak_metrics["1"];
+        assert_eq!(peaks.peak_jvm_used_heap, 536870912);
+        assert_eq!(peaks.peak_jvm_used_non_heap, 104857600);
+        assert_eq!(peaks.peak_process_tree_rss_bytes, 1073741824);
+        assert_eq!(stage.executor_peak_metrics["driver"].peak_process_tree_rss_bytes, 0);
+    }
+
+    #[test]
+    fn parse_event_log_sums_task_time_per_executor() {
+        let (event_log, _) = parse_fixture("executor-task-time", &[
+            APPLICATION_START,
+            r#"{"Event":"SparkListenerExecutorAdded","Executor ID":"1","Executor Info":{"Host":"worker1","Total Cores":4}}"#,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":0,"Task Info":{"Task ID":1,"Launch Time":1733052001000,"Executor ID":"1","Host":"worker1"}}"#,
+            r#"{"Event":"SparkListenerTaskStart","Stage ID":0,"Task Info":{"Task ID":2,"Launch Time":1733052001000,"Executor ID":"1","Host":"worker1"}}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Stage ID":0,"Task Info":{"Task ID":1,"Finish Time":1733052002000},"Task Metrics":{"Executor Run Time":600,"JVM GC Time":30}}"#,
+            r#"{"Event":"SparkListenerTaskEnd","Stage ID":0,"Task Info":{"Task ID":2,"Finish Time":1733052002000},"Task Metrics":{"Executor Run Time":400,"JVM GC Time":120}}"#,
+        ])
+        .unwrap();
+
+        let executor = &event_log.executors["1"];
+        assert_eq!(executor.total_duration, 1000);
+        assert_eq!(executor.total_gc_time, 150);
+    }
+
+    #[test]
+    fn parse_task_failure_reason_combines_class_and_description() {
+        let failed = json!({
+            "Task End Reason": {
+                "Reason": "ExceptionFailure",
+                "Class Name": "java.lang.OutOfMemoryError",
+                "Description": "Java heap space"
+            }
+        });
+        assert_eq!(parse_task_failure_reason(&failed).as_deref(), Some("java.lang.OutOfMemoryError: Java heap space"));
+        assert_eq!(parse_task_failure_reason(&json!({"Task End Reason": {"Reason": "TaskKilled"}})).as_deref(), Some("TaskKilled"));
+        assert_eq!(parse_task_failure_reason(&json!({"Task End Reason": {"Reason": "Success"}})), None);
+    }
+
+    #[test]
+    fn parse_event_log_reads_snappy_compressed_logs() {
+        use std::io::Write;
+
+        let path = std::env::temp_dir().join(format!("spark-tui-{}.snappy", std::process::id()));
+        {
+            let file = File::create(&path).unwrap();
+            let mut encoder = snap::write::FrameEncoder::new(file);
+            writeln!(
+                encoder,
+                r#"{{"Event":"SparkListenerApplicationStart","App Name":"Snappy App","App ID":"app-1","Timestamp":1733052000000,"User":"test"}}"#
+            )
+            .unwrap();
+        }
+
+        let event_log = parse_event_log(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let (event_log, metadata) = event_log.unwrap();
+        assert_eq!(event_log.application_info.app_name, "Snappy App");
+        assert_eq!(metadata.line_count, 1);
+    }
+}
@@ -0,0 +1,116 @@
+use std::collections::HashSet;
+
+use crate::models::SparkEventLog;
+
+/// The parts of one event log line that ordering checks look at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedEvent {
+    pub event_type: String,
+    pub sql_execution_id: Option<u64>,
+}
+
+/// Cross-check the parsed log for references and timestamps that cannot all
+/// be right, which usually means the log is truncated or was stitched
+/// together from several files. Returns one message per violation.
+pub fn validate_event_log(event_log: &SparkEventLog) -> Vec<String> {
+    let mut issues = Vec::new();
+    let stage_ids: HashSet<u64> = event_log.stages.keys().map(|(stage_id, _)| *stage_id).collect();
+    let app_start = event_log.application_info.start_time;
+
+    let mut jobs: Vec<_> = event_log.jobs.values().collect();
+    jobs.sort_by_key(|job| job.job_id);
+    for job in jobs {
+        for stage_id in job.stage_ids.iter().filter(|id| !stage_ids.contains(id)) {
+            issues.push(format!("Job {} stage_id {} not found in stages", job.job_id, stage_id));
+        }
+        if job.completion_time.is_some_and(|end| end < job.submission_time) {
+            issues.push(format!("Job {} completion_time is before its submission_time", job.job_id));
+        }
+        if let Some(execution_id) = job.properties.get("spark.sql.execution.id") {
+            let known = execution_id.parse::<u64>().is_ok_and(|id| event_log.sql_executions.contains_key(&id));
+            if !known {
+                issues.push(format!("Job {} spark.sql.execution.id {} not found in SQL executions", job.job_id, execution_id));
+            }
+        }
+    }
+
+    let mut stages: Vec<_> = event_log.stages.values().collect();
+    stages.sort_by_key(|stage| (stage.stage_id, stage.stage_attempt_id));
+    for stage in stages {
+        if let (Some(start), Some(end)) = (stage.submission_time, stage.completion_time) {
+            if end < start {
+                issues.push(format!(
+                    "Stage {}.{} completion_time is before its submission_time",
+                    stage.stage_id, stage.stage_attempt_id
+                ));
+            }
+        }
+    }
+
+    let mut tasks: Vec<_> = event_log.tasks.values().collect();
+    tasks.sort_by_key(|task| task.task_id);
+    for task in tasks {
+        if !stage_ids.contains(&task.stage_id) {
+            issues.push(format!("Task {} stage_id {} not found in stages", task.task_id, task.stage_id));
+        }
+        if task.launch_time < app_start {
+            issues.push(format!("Task {} launch_time is before the application start_time", task.task_id));
+        }
+        if task.finish_time.is_some_and(|end| end < task.launch_time) {
+            issues.push(format!("Task {} finish_time is before its launch_time", task.task_id));
+        }
+    }
+
+    let mut executions: Vec<_> = event_log.sql_executions.iter().collect();
+    executions.sort_by_key(|(id, _)| **id);
+    for (id, execution) in executions {
+        if execution.execution_id != *id {
+            issues.push(format!("SQL execution {} is stored under execution ID {}", execution.execution_id, id));
+        }
+        if execution.completion_time.is_some_and(|end| end < execution.submission_time) {
+            issues.push(format!("SQL execution {} completion_time is before its submission_time", id));
+        }
+    }
+
+    issues
+}
+
+/// Check that events arrive in an order Spark could have written them in.
+/// SQL execution IDs are handed out sequentially, so a start event whose ID
+/// is not above every earlier one is suspect. Concurrent queries can post
+/// their starts out of ID order, so treat the result as a warning only.
+pub fn validate_event_ordering(events: &[ParsedEvent]) -> Vec<String> {
+    let mut issues = Vec::new();
+    let mut latest_execution_id: Option<u64> = None;
+    for event in events.iter().filter(|event| event.event_type.ends_with("SQLExecutionStart")) {
+        let Some(execution_id) = event.sql_execution_id else {
+            continue;
+        };
+        match latest_execution_id {
+            Some(latest) if execution_id <= latest => {
+                issues.push(format!("SQL execution {} started after SQL execution {}", execution_id, latest));
+            }
+            _ => latest_execution_id = Some(execution_id),
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sql_start(execution_id: u64) -> ParsedEvent {
+        ParsedEvent { event_type: "SparkListenerSQLExecutionStart".to_string(), sql_execution_id: Some(execution_id) }
+    }
+
+    #[test]
+    fn flags_sql_execution_ids_that_go_backwards() {
+        let job_start = ParsedEvent { event_type: "SparkListenerJobStart".to_string(), sql_execution_id: Some(0) };
+        let events = [sql_start(3), job_start, sql_start(1), sql_start(4), sql_start(4)];
+        assert_eq!(validate_event_ordering(&events), vec![
+            "SQL execution 1 started after SQL execution 3",
+            "SQL execution 4 started after SQL execution 4",
+        ]);
+    }
+}
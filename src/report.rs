@@ -0,0 +1,257 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::models::{ApplicationSummary, Job, JobStatus, SparkEventLog, Stage, Task};
+use crate::ui::format_duration;
+use crate::util::sort_by_duration_desc;
+
+/// A `<table>:<column>` pair accepted by `--sort-by`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    JobsDuration,
+    JobsStatus,
+    StagesDuration,
+    StagesNumTasks,
+    TasksDuration,
+    TasksGcTime,
+    ExecutorsTotalTasks,
+}
+
+impl SortKey {
+    const ALL: [(&'static str, SortKey); 7] = [
+        ("jobs:duration", SortKey::JobsDuration),
+        ("jobs:status", SortKey::JobsStatus),
+        ("stages:duration", SortKey::StagesDuration),
+        ("stages:num_tasks", SortKey::StagesNumTasks),
+        ("tasks:duration", SortKey::TasksDuration),
+        ("tasks:gc_time", SortKey::TasksGcTime),
+        ("executors:total_tasks", SortKey::ExecutorsTotalTasks),
+    ];
+}
+
+impl FromStr for SortKey {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SortKey::ALL
+            .iter()
+            .find(|(name, _)| *name == s)
+            .map(|(_, key)| *key)
+            .ok_or_else(|| {
+                let supported: Vec<_> = SortKey::ALL.iter().map(|(name, _)| *name).collect();
+                format!("unrecognized sort column '{}' (supported: {})", s, supported.join(", "))
+            })
+    }
+}
+
+/// How `--test` mode prints the application summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!("unrecognized output format '{}' (supported: text, json, csv)", s)),
+        }
+    }
+}
+
+const SUMMARY_CSV_HEADER: &str = "app_name,app_id,duration_ms,total_jobs,failed_jobs,total_stages,failed_stages,\
+total_tasks,failed_tasks,total_executor_time_ms,total_gc_time_ms,gc_overhead_pct,total_input_bytes,\
+total_output_bytes,total_shuffle_read_bytes,total_shuffle_write_bytes,total_memory_spilled_bytes,\
+total_disk_spilled_bytes";
+
+/// The summary as a CSV header line followed by a single data row.
+pub fn summary_csv(summary: &ApplicationSummary) -> String {
+    let fields = [
+        csv_field(&summary.app_name),
+        csv_field(&summary.app_id),
+        summary.duration_ms.map(|ms| ms.to_string()).unwrap_or_default(),
+        summary.total_jobs.to_string(),
+        summary.failed_jobs.to_string(),
+        summary.total_stages.to_string(),
+        summary.failed_stages.to_string(),
+        summary.total_tasks.to_string(),
+        summary.failed_tasks.to_string(),
+        summary.total_executor_time_ms.to_string(),
+        summary.total_gc_time_ms.to_string(),
+        format!("{:.2}", summary.gc_overhead_pct),
+        summary.total_input_bytes.to_string(),
+        summary.total_output_bytes.to_string(),
+        summary.total_shuffle_read_bytes.to_string(),
+        summary.total_shuffle_write_bytes.to_string(),
+        summary.total_memory_spilled_bytes.to_string(),
+        summary.total_disk_spilled_bytes.to_string(),
+    ];
+    format!("{}\n{}", SUMMARY_CSV_HEADER, fields.join(","))
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Print the table selected by `key`, sorted by that column in descending order.
+pub fn print_sorted_table(event_log: &SparkEventLog, key: SortKey) {
+    match key {
+        SortKey::JobsDuration | SortKey::JobsStatus => print_jobs(event_log, key),
+        SortKey::StagesDuration | SortKey::StagesNumTasks => print_stages(event_log, key),
+        SortKey::TasksDuration | SortKey::TasksGcTime => print_tasks(event_log, key),
+        SortKey::ExecutorsTotalTasks => print_executors(event_log),
+    }
+}
+
+fn format_duration_ms(duration: Option<i64>) -> String {
+    duration.map(|ms| format_duration(ms.max(0) as u64)).unwrap_or_else(|| "Running".to_string())
+}
+
+fn print_jobs(event_log: &SparkEventLog, key: SortKey) {
+    let mut jobs: Vec<_> = event_log.jobs.values().collect();
+    let duration = |job: &Job| {
+        job.completion_time.map(|end| (end - job.submission_time).num_milliseconds())
+    };
+
+    if key == SortKey::JobsStatus {
+        // Failures first, then in-flight work, then successes
+        let rank = |status: &JobStatus| match status {
+            JobStatus::Failed => 3,
+            JobStatus::Running => 2,
+            JobStatus::Unknown => 1,
+            JobStatus::Succeeded => 0,
+        };
+        jobs.sort_by(|a, b| rank(&b.status).cmp(&rank(&a.status)).then(a.job_id.cmp(&b.job_id)));
+    } else {
+        jobs.sort_by_key(|job| job.job_id);
+        sort_by_duration_desc(&mut jobs, |job| duration(job));
+    }
+
+    println!("{:<8} {:<10} {:>12} {:>7}", "Job ID", "Status", "Duration", "Stages");
+    for job in jobs {
+        println!(
+            "{:<8} {:<10} {:>12} {:>7}",
+            job.job_id,
+            job.status.to_string(),
+            format_duration_ms(duration(job)),
+            job.stage_ids.len()
+        );
+    }
+}
+
+fn print_stages(event_log: &SparkEventLog, key: SortKey) {
+    let mut stages: Vec<_> = event_log.stages.values().collect();
+    let duration = |stage: &Stage| match (stage.submission_time, stage.completion_time) {
+        (Some(start), Some(end)) => Some((end - start).num_milliseconds()),
+        _ => None,
+    };
+
+    if key == SortKey::StagesNumTasks {
+        stages.sort_by(|a, b| b.num_tasks.cmp(&a.num_tasks).then((a.stage_id, a.stage_attempt_id).cmp(&(b.stage_id, b.stage_attempt_id))));
+    } else {
+        stages.sort_by_key(|stage| (stage.stage_id, stage.stage_attempt_id));
+        sort_by_duration_desc(&mut stages, |stage| duration(stage));
+    }
+
+    println!("{:<8} {:<10} {:>7} {:>12}  Name", "Stage ID", "Status", "Tasks", "Duration");
+    for stage in stages {
+        println!(
+            "{:<8} {:<10} {:>7} {:>12}  {}",
+            stage.stage_id,
+            stage.status.to_string(),
+            stage.num_tasks,
+            format_duration_ms(duration(stage)),
+            stage.name
+        );
+    }
+}
+
+fn print_tasks(event_log: &SparkEventLog, key: SortKey) {
+    let mut tasks: Vec<_> = event_log.tasks.values().collect();
+    let duration = |task: &Task| {
+        task.finish_time.map(|end| (end - task.launch_time).num_milliseconds())
+    };
+    let gc_time = |task: &Task| task.metrics.as_ref().map(|m| m.gc_time);
+
+    if key == SortKey::TasksGcTime {
+        tasks.sort_by(|a, b| gc_time(b).cmp(&gc_time(a)).then(a.task_id.cmp(&b.task_id)));
+    } else {
+        tasks.sort_by_key(|task| task.task_id);
+        sort_by_duration_desc(&mut tasks, |task| duration(task));
+    }
+
+    println!("{:<8} {:<7} {:<10} {:<8} {:>12} {:>10}", "Task ID", "Stage", "Executor", "Status", "Duration", "GC Time");
+    for task in tasks {
+        println!(
+            "{:<8} {:<7} {:<10} {:<8} {:>12} {:>10}",
+            task.task_id,
+            format!("{}.{}", task.stage_id, task.stage_attempt_id),
+            task.executor_id,
+            task.status.to_string(),
+            format_duration_ms(duration(task)),
+            gc_time(task).map(format_duration).unwrap_or_else(|| "N/A".to_string())
+        );
+    }
+}
+
+fn print_executors(event_log: &SparkEventLog) {
+    // Count from the tasks themselves; the per-executor counters are not
+    // maintained by the parser
+    let mut task_counts: HashMap<&str, usize> = HashMap::new();
+    for task in event_log.tasks.values() {
+        *task_counts.entry(task.executor_id.as_str()).or_insert(0) += 1;
+    }
+
+    let mut executors: Vec<_> = event_log.executors.values().collect();
+    let total_tasks = |id: &str| task_counts.get(id).copied().unwrap_or(0);
+    executors.sort_by(|a, b| {
+        total_tasks(&b.executor_id)
+            .cmp(&total_tasks(&a.executor_id))
+            .then(a.executor_id.cmp(&b.executor_id))
+    });
+
+    println!("{:<12} {:<20} {:>6} {:>12}", "Executor ID", "Host", "Cores", "Total Tasks");
+    for executor in executors {
+        println!(
+            "{:<12} {:<20} {:>6} {:>12}",
+            executor.executor_id,
+            executor.host,
+            executor.total_cores,
+            total_tasks(&executor.executor_id)
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_csv_quotes_names_and_leaves_running_duration_empty() {
+        let summary = ApplicationSummary {
+            app_name: "ETL, \"nightly\"".to_string(),
+            app_id: "app-1".to_string(),
+            total_jobs: 3,
+            ..ApplicationSummary::default()
+        };
+
+        let csv = summary_csv(&summary);
+        let mut lines = csv.lines();
+        let header = lines.next().unwrap();
+        let row = lines.next().unwrap();
+        assert!(header.starts_with("app_name,app_id,duration_ms,total_jobs,"));
+        assert!(row.starts_with("\"ETL, \"\"nightly\"\"\",app-1,,3,"));
+        assert_eq!(header.split(',').count(), 18);
+    }
+}
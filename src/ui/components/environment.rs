@@ -1,11 +1,14 @@
+use chrono_tz::Tz;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
-use crate::models::SparkEventLog;
+use super::{format_time, row_style};
+use crate::config::Theme;
+use crate::models::{MiscProcess, SparkEventLog};
 use std::collections::HashMap;
 
 pub struct EnvironmentTab;
@@ -15,7 +18,19 @@ impl EnvironmentTab {
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        zebra_stripe: bool,
+        show_misc_processes: bool,
+        timezone: Tz,
+        theme: &Theme,
     ) {
+        let processes = &event_log.misc_processes;
+        // Collapsed, the section is a single bordered line
+        let misc_height = match (processes.is_empty(), show_misc_processes) {
+            (true, _) => 0,
+            (false, false) => 3,
+            (false, true) => (processes.len() as u16 + 4).min(12),
+        };
+
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
@@ -23,15 +38,22 @@ impl EnvironmentTab {
                 Constraint::Percentage(25), // System Properties
                 Constraint::Percentage(25), // Hadoop Properties
                 Constraint::Percentage(25), // Classpath Entries
+                Constraint::Length(misc_height), // Miscellaneous Processes
             ])
             .split(area);
 
+        if !processes.is_empty() {
+            Self::draw_misc_processes(f, chunks[4], processes, show_misc_processes, zebra_stripe, timezone, theme);
+        }
+
         // Spark Properties
         Self::draw_properties_table(
             f,
             chunks[0],
             "Spark Properties",
             &event_log.environment.spark_properties,
+            zebra_stripe,
+            theme,
         );
 
         // System Properties
@@ -40,6 +62,8 @@ impl EnvironmentTab {
             chunks[1],
             "System Properties",
             &event_log.environment.system_properties,
+            zebra_stripe,
+            theme,
         );
 
         // Hadoop Properties
@@ -48,6 +72,8 @@ impl EnvironmentTab {
             chunks[2],
             "Hadoop Properties",
             &event_log.environment.hadoop_properties,
+            zebra_stripe,
+            theme,
         );
 
         // Classpath Entries
@@ -56,33 +82,90 @@ impl EnvironmentTab {
             chunks[3],
             "Classpath Entries",
             &event_log.environment.classpath_entries,
+            zebra_stripe,
+            theme,
         );
     }
 
+    fn draw_misc_processes(
+        f: &mut Frame,
+        area: ratatui::layout::Rect,
+        processes: &[MiscProcess],
+        expanded: bool,
+        zebra_stripe: bool,
+        timezone: Tz,
+        theme: &Theme,
+    ) {
+        if !expanded {
+            let paragraph = ratatui::widgets::Paragraph::new("Press m to expand")
+                .block(Block::default().borders(Borders::ALL).title(format!("Miscellaneous Processes ({})", processes.len())))
+                .style(Style::default().fg(theme.muted_fg));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let header_cells = ["Process", "Host", "Port", "Added"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
+
+        let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+        let rows = processes.iter().enumerate().map(|(i, process)| {
+            Row::new(vec![
+                Cell::from(process.name.clone()),
+                Cell::from(process.host.clone()),
+                Cell::from(process.port.to_string()),
+                Cell::from(
+                    process.timestamp
+                        .map(|t| format_time(t, "%Y-%m-%d %H:%M:%S", timezone))
+                        .unwrap_or_else(|| "N/A".to_string()),
+                ),
+            ])
+            .style(row_style(i, zebra_stripe, theme))
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Percentage(30), // Process
+                Constraint::Percentage(30), // Host
+                Constraint::Length(6),      // Port
+                Constraint::Length(19),     // Added
+            ]
+        )
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(format!("Miscellaneous Processes ({}) - m to collapse", processes.len())))
+            .column_spacing(1);
+
+        f.render_widget(table, area);
+    }
+
     fn draw_properties_table(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         title: &str,
         properties: &HashMap<String, String>,
+        zebra_stripe: bool,
+        theme: &Theme,
     ) {
         if properties.is_empty() {
             let paragraph = ratatui::widgets::Paragraph::new("No properties available")
                 .block(Block::default().borders(Borders::ALL).title(title))
-                .style(Style::default().fg(Color::Gray));
+                .style(Style::default().fg(theme.muted_fg));
             f.render_widget(paragraph, area);
             return;
         }
 
         let header_cells = ["Property", "Value"]
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
         let mut properties_vec: Vec<_> = properties.iter().collect();
         properties_vec.sort_by_key(|(key, _)| key.as_str());
 
-        let rows = properties_vec.iter().map(|(key, value)| {
+        let rows = properties_vec.iter().enumerate().map(|(i, (key, value))| {
             // Truncate long values for display
             let display_value = if value.len() > 60 {
                 format!("{}...", &value[..57])
@@ -94,6 +177,7 @@ impl EnvironmentTab {
                 Cell::from(key.as_str()),
                 Cell::from(display_value),
             ])
+            .style(row_style(i, zebra_stripe, theme))
         });
 
         let table = Table::new(
@@ -108,7 +192,8 @@ impl EnvironmentTab {
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             );
 
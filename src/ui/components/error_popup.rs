@@ -0,0 +1,31 @@
+use ratatui::{
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::centered_rect;
+use crate::config::Theme;
+
+/// An error reported while the TUI was running, drawn over everything else.
+pub struct ErrorPopup;
+
+impl ErrorPopup {
+    pub fn draw(f: &mut Frame, area: ratatui::layout::Rect, message: &str, theme: &Theme) {
+        let popup_area = centered_rect(60, 30, area);
+        f.render_widget(Clear, popup_area);
+
+        let paragraph = Paragraph::new(message.to_string())
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(theme.status_failed))
+                    .title("Error - press any key to dismiss")
+                    .title_style(Style::default().fg(theme.status_failed).add_modifier(Modifier::BOLD)),
+            )
+            .style(Style::default().fg(theme.status_failed).bg(theme.background))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
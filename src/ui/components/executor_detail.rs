@@ -0,0 +1,96 @@
+use ratatui::{
+    style::{Style},
+    text::Line,
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{centered_rect, detail_line, format_bytes, section_line};
+use crate::config::Theme;
+use crate::models::{BlockManagerMigration, Executor};
+
+pub struct ExecutorDetailPopup;
+
+impl ExecutorDetailPopup {
+    pub fn draw(f: &mut Frame, area: ratatui::layout::Rect, executor: &Executor, migrations: &[BlockManagerMigration], theme: &Theme) {
+        let popup_area = centered_rect(60, 60, area);
+        f.render_widget(Clear, popup_area);
+
+        let k8s_value = |key: &str| {
+            executor.k8s_attributes.get(key).cloned().unwrap_or_else(|| "N/A".to_string())
+        };
+
+        let mut lines = vec![
+            detail_line("Executor", executor.executor_id.clone(), theme),
+            if executor.port > 0 {
+                detail_line("Host", format!("{}:{}", executor.host, executor.port), theme)
+            } else {
+                detail_line("Host", executor.host.clone(), theme)
+            },
+            detail_line("Status", if executor.is_active { "ACTIVE" } else { "REMOVED" }.to_string(), theme),
+            detail_line("Cores", executor.total_cores.to_string(), theme),
+            detail_line(
+                "Resource Profile",
+                executor.resource_profile_id.map(|id| format!("#{}", id)).unwrap_or_else(|| "N/A".to_string()),
+                theme,
+            ),
+            detail_line("Max Memory", format_bytes(executor.max_memory), theme),
+            detail_line(
+                "Storage Memory",
+                format!("Heap: {} | Off-Heap: {}", format_bytes(executor.max_on_heap_memory), format_bytes(executor.max_off_heap_memory)),
+                theme,
+            ),
+            detail_line(
+                "Block Migrations",
+                migrations.iter()
+                    .filter(|m| m.source_executor_id == executor.executor_id || m.target_executor_id == executor.executor_id)
+                    .count()
+                    .to_string(),
+                theme,
+            ),
+            Line::from(""),
+            section_line("Kubernetes", theme),
+        ];
+
+        if executor.k8s_attributes.is_empty() {
+            lines.push(Line::from("No Kubernetes attributes"));
+        } else {
+            lines.push(detail_line("Pod", k8s_value("kubernetes.pod.name"), theme));
+            lines.push(detail_line("Namespace", k8s_value("kubernetes.pod.namespace"), theme));
+            lines.push(detail_line("Node", k8s_value("kubernetes.node.name"), theme));
+        }
+
+        if !executor.resources.is_empty() {
+            // GPUs first, they are what these executors are usually for
+            let mut resources: Vec<_> = executor.resources.iter().collect();
+            resources.sort_by_key(|(name, _)| (name.as_str() != "gpu", name.as_str()));
+            lines.push(Line::from(""));
+            lines.push(section_line("Resources", theme));
+            for (name, resource) in resources {
+                let value = if resource.addresses.is_empty() {
+                    resource.amount.to_string()
+                } else {
+                    format!("{} (addresses: {})", resource.amount, resource.addresses.join(", "))
+                };
+                lines.push(detail_line(name, value, theme));
+            }
+        }
+
+        let mut attributes: Vec<_> = executor.attributes.iter().filter(|(_, value)| !value.is_empty()).collect();
+        attributes.sort();
+        if !attributes.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(section_line("Attributes", theme));
+            for (key, value) in attributes {
+                lines.push(detail_line(key, value.clone(), theme));
+            }
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!("Executor {} - Enter/Esc to close", executor.executor_id)))
+            .style(Style::default().fg(theme.text_fg).bg(theme.background))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
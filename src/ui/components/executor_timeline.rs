@@ -0,0 +1,123 @@
+use ratatui::{
+    style::{Color, Style},
+    symbols::Marker,
+    text::Span,
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine, Rectangle},
+        Block, Borders, Paragraph,
+    },
+    Frame,
+};
+
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, Executor, SparkEventLog, TaskStatus};
+
+/// Candidate tick spacings for the time axis, in seconds.
+const TICK_STEPS_SECS: &[u64] = &[1, 2, 5, 10, 15, 30, 60, 120, 300, 600, 900, 1800, 3600, 7200];
+const MAX_TICKS: u64 = 10;
+
+pub struct ExecutorTimeline;
+
+impl ExecutorTimeline {
+    /// Gantt chart of task run times, one lane per executor.
+    pub fn draw(
+        f: &mut Frame,
+        area: ratatui::layout::Rect,
+        event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
+        executors: &[&Executor],
+        theme: &Theme,
+    ) {
+        let block = Block::default().borders(Borders::ALL).title("Executor Timeline (t: table view)");
+
+        let start = event_log.application_info.start_time;
+        let end = event_log.application_info.end_time
+            .or_else(|| event_log.tasks.values().filter_map(|t| t.finish_time).max())
+            .unwrap_or(start);
+        let span_ms = (end - start).num_milliseconds().max(1) as f64;
+
+        if executors.is_empty() || event_log.tasks.is_empty() {
+            let paragraph = Paragraph::new("No tasks to plot")
+                .block(block)
+                .style(Style::default().fg(theme.muted_fg));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        // Reserve space left of the time origin for executor labels
+        let label_width = span_ms * 0.08;
+        let lanes = executors.len() as f64;
+        let tick_step_ms = (tick_step_secs(span_ms as u64 / 1000) * 1000) as f64;
+
+        let canvas = Canvas::default()
+            .block(block)
+            .marker(Marker::Braille)
+            .x_bounds([-label_width, span_ms])
+            .y_bounds([-1.0, lanes])
+            .paint(move |ctx| {
+                for (lane, executor) in executors.iter().enumerate() {
+                    // First executor at the top
+                    let y = lanes - 1.0 - lane as f64;
+                    ctx.print(-label_width, y + 0.3, Span::raw(executor.executor_id.clone()));
+
+                    let task_ids = aggregates.executor_tasks.get(&executor.executor_id).map(Vec::as_slice).unwrap_or_default();
+                    for task in task_ids.iter().map(|id| &event_log.tasks[id]) {
+                        let task_start = (task.launch_time - start).num_milliseconds() as f64;
+                        let task_end = task.finish_time
+                            .map(|t| (t - start).num_milliseconds() as f64)
+                            .unwrap_or(span_ms);
+
+                        ctx.draw(&Rectangle {
+                            x: task_start,
+                            y: y + 0.1,
+                            width: (task_end - task_start).max(0.0),
+                            height: 0.8,
+                            color: status_color(&task.status, theme),
+                        });
+                    }
+                }
+
+                // Time axis along the bottom
+                ctx.draw(&CanvasLine {
+                    x1: 0.0,
+                    y1: -0.2,
+                    x2: span_ms,
+                    y2: -0.2,
+                    color: theme.muted_fg,
+                });
+
+                let mut tick = 0.0;
+                while tick <= span_ms {
+                    ctx.draw(&CanvasLine {
+                        x1: tick,
+                        y1: -0.35,
+                        x2: tick,
+                        y2: -0.2,
+                        color: theme.muted_fg,
+                    });
+                    ctx.print(tick, -0.9, Span::styled(format!("{}s", (tick / 1000.0) as u64), Style::default().fg(theme.muted_fg)));
+                    tick += tick_step_ms;
+                }
+            });
+
+        f.render_widget(canvas, area);
+    }
+}
+
+fn status_color(status: &TaskStatus, theme: &Theme) -> Color {
+    match status {
+        TaskStatus::Running => theme.status_running,
+        TaskStatus::Success => theme.status_success,
+        TaskStatus::Failed => theme.status_failed,
+        TaskStatus::Killed => theme.status_killed,
+    }
+}
+
+/// Smallest tick spacing that keeps the axis to at most `MAX_TICKS` ticks.
+fn tick_step_secs(span_secs: u64) -> u64 {
+    TICK_STEPS_SECS
+        .iter()
+        .copied()
+        .find(|step| span_secs / step <= MAX_TICKS)
+        .unwrap_or_else(|| span_secs / MAX_TICKS + 1)
+}
@@ -1,26 +1,56 @@
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::Span,
-    widgets::{Block, Borders, Cell, Row, Table, TableState},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Sparkline, Table, TableState},
     Frame,
 };
 
-use crate::models::SparkEventLog;
+use super::{filtered_title, format_bytes, row_style, ExecutorTimeline};
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, Executor, ResourceProfile, SparkEventLog};
+use std::collections::HashSet;
 
 pub struct ExecutorsTab;
 
 impl ExecutorsTab {
+    /// Executors in table order, so a selected row index maps back to its executor.
+    pub fn sorted_executors<'a>(event_log: &'a SparkEventLog, aggregates: &EventLogAggregates, failed_only: bool) -> Vec<&'a Executor> {
+        // The parser does not maintain the per-executor task counters, so
+        // read the counts taken from the tasks themselves
+        let has_failed_tasks = |executor: &Executor| {
+            aggregates.executor_task_counts.get(&executor.executor_id).is_some_and(|s| s.failed_tasks > 0)
+        };
+
+        let mut executors: Vec<_> = event_log.executors.values()
+            .filter(|e| !failed_only || has_failed_tasks(e))
+            .collect();
+        executors.sort_by(|a, b| {
+            // Sort by executor ID, treating numeric parts as numbers
+            match (a.executor_id.parse::<i32>(), b.executor_id.parse::<i32>()) {
+                (Ok(a_num), Ok(b_num)) => a_num.cmp(&b_num),
+                _ => a.executor_id.cmp(&b.executor_id),
+            }
+        });
+        executors
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
         table_state: &TableState,
+        show_timeline: bool,
+        zebra_stripe: bool,
+        failed_only: bool,
+        theme: &Theme,
     ) {
-        let chunks = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([Constraint::Length(6), Constraint::Min(0)])
-            .split(area);
+        let executors = Self::sorted_executors(event_log, aggregates, failed_only);
+        let sampled: Vec<_> = executors.iter().copied().filter(|e| !e.memory_samples.is_empty()).collect();
+        // One line per sampled executor plus borders, capped so the table keeps most of the space
+        let memory_height = if sampled.is_empty() { 3 } else { (sampled.len() as u16 + 2).min(12) };
 
         // Summary section
         let active_executors = event_log.executors.values().filter(|e| e.is_active).count();
@@ -37,40 +67,111 @@ impl ExecutorsTab {
             memory_used / (1024 * 1024),
         );
 
-        let tasks_summary = format!(
+        let task_counts = &aggregates.task_status_counts;
+        let mut tasks_summary = format!(
             "Total Tasks: {} | Completed: {} | Failed: {}",
-            event_log.executors.values().map(|e| e.total_tasks).sum::<u32>(),
-            event_log.executors.values().map(|e| e.completed_tasks).sum::<u32>(),
-            event_log.executors.values().map(|e| e.failed_tasks).sum::<u32>(),
+            aggregates.task_order.len(),
+            task_counts.succeeded,
+            task_counts.failed,
         );
+        let dynamic_allocation = event_log.environment.spark_properties.get("spark.dynamicAllocation.enabled")
+            .is_some_and(|v| v.trim() == "true");
+        if dynamic_allocation {
+            tasks_summary.push_str(" | Dynamic Alloc: ON");
+        }
+
+        let mut summary_lines = vec![summary_text, tasks_summary];
+
+        // Standalone clusters report nodes and workers separately; several executors may share one
+        let mut cluster_parts = Vec::new();
+        if !event_log.cluster_nodes.is_empty() {
+            cluster_parts.push(format!(
+                "Nodes: {} ({} active)",
+                event_log.cluster_nodes.len(),
+                event_log.cluster_nodes.values().filter(|n| n.is_active).count(),
+            ));
+        }
+        if !event_log.workers.is_empty() {
+            cluster_parts.push(format!(
+                "Workers: {} ({} active) | Worker Cores: {} | Worker Memory: {}",
+                event_log.workers.len(),
+                event_log.workers.values().filter(|w| w.is_active).count(),
+                event_log.workers.values().map(|w| w.cores).sum::<u32>(),
+                format_bytes(event_log.workers.values().map(|w| w.memory).sum()),
+            ));
+        }
+        if !cluster_parts.is_empty() {
+            summary_lines.push(format!("Cluster: {}", cluster_parts.join(" | ")));
+        }
+
+        // The driver has no executor row, but large broadcasts can exhaust its memory
+        if let Some(driver) = &event_log.driver_block_manager {
+            let mut driver_memory = format!("Driver Memory: {} on-heap", format_bytes(driver.max_on_heap_memory));
+            if driver.max_off_heap_memory > 0 {
+                driver_memory.push_str(&format!(" + {} off-heap", format_bytes(driver.max_off_heap_memory)));
+            }
+            summary_lines.push(driver_memory);
+        }
 
-        let summary = ratatui::widgets::Paragraph::new(format!("{}\n{}", summary_text, tasks_summary))
+        if !event_log.block_manager_migrations.is_empty() {
+            summary_lines.push(format!(
+                "⚠ Block migrations: {} ({} blocks moved off decommissioned executors)",
+                event_log.block_manager_migrations.len(),
+                event_log.block_manager_migrations.iter().map(|m| m.num_blocks).sum::<u64>(),
+            ));
+        }
+
+        let imbalanced = &aggregates.imbalanced_executors;
+        if !imbalanced.is_empty() {
+            summary_lines.push(format!("⚠ Imbalanced Executors: {} (task count >30% from the mean)", imbalanced.len()));
+        }
+
+        let excluded_hosts: HashSet<&str> = event_log.excluded_nodes.iter().map(|(host, _)| host.as_str()).collect();
+        if !excluded_hosts.is_empty() {
+            summary_lines.push(format!("⚠ {} nodes excluded", excluded_hosts.len()));
+        }
+
+        if !event_log.resource_profiles.is_empty() {
+            let mut profiles: Vec<_> = event_log.resource_profiles.values().collect();
+            profiles.sort_by_key(|p| p.profile_id);
+            summary_lines.push(format!(
+                "Resource Profiles: {}",
+                profiles.iter().map(|p| format_resource_profile(p)).collect::<Vec<_>>().join("; "),
+            ));
+        }
+
+        // Borders plus at least four lines, so the table does not jump as optional lines appear
+        let summary_height = summary_lines.len().max(4) as u16 + 2;
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(summary_height), Constraint::Min(0), Constraint::Length(memory_height)])
+            .split(area);
+
+        Self::draw_memory_pressure(f, chunks[2], &sampled, theme);
+
+        let summary = Paragraph::new(summary_lines.join("\n"))
             .block(Block::default().borders(Borders::ALL).title("Executors Summary"))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text_fg));
 
         f.render_widget(summary, chunks[0]);
 
         // Executors table
-        let header_cells = ["Executor ID", "Host", "Status", "Cores", "Memory", "Tasks", "Failed", "GC Time", "Input", "Shuffle Read", "Shuffle Write"]
+        let header_cells = ["Executor ID", "Host", "Status", "Cores", "Memory", "Tasks", "Failed", "GC Time", "GC%", "Input", "Shuffle Read", "Shuffle Write", "Throughput"]
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-        let mut executors: Vec<_> = event_log.executors.values().collect();
-        executors.sort_by(|a, b| {
-            // Sort by executor ID, treating numeric parts as numbers
-            match (a.executor_id.parse::<i32>(), b.executor_id.parse::<i32>()) {
-                (Ok(a_num), Ok(b_num)) => a_num.cmp(&b_num),
-                _ => a.executor_id.cmp(&b.executor_id),
-            }
-        });
+        if show_timeline {
+            ExecutorTimeline::draw(f, chunks[1], event_log, aggregates, &executors, theme);
+            return;
+        }
 
-        let rows = executors.iter().map(|executor| {
+        let rows = executors.iter().enumerate().map(|(i, executor)| {
             let status_style = if executor.is_active {
-                Style::default().fg(Color::Green)
+                Style::default().fg(theme.status_success)
             } else {
-                Style::default().fg(Color::Red)
+                Style::default().fg(theme.status_failed)
             };
 
             let status_text = if executor.is_active { "ACTIVE" } else { "REMOVED" };
@@ -88,19 +189,48 @@ impl ExecutorsTab {
 
             let gc_time_sec = executor.total_gc_time as f64 / 1000.0;
 
+            let gc_overhead = if executor.total_duration > 0 {
+                let pct = executor.total_gc_time as f64 / executor.total_duration as f64 * 100.0;
+                let color = if pct < 5.0 {
+                    theme.status_success
+                } else if pct <= 15.0 {
+                    theme.status_warning
+                } else {
+                    theme.status_failed
+                };
+                Span::styled(format!("{:.1}%", pct), Style::default().fg(color))
+            } else {
+                Span::styled("N/A", Style::default().fg(theme.muted_fg))
+            };
+
+            // Task counts, records read and run time, from the tasks the executor ran
+            let task_summary = aggregates.executor_task_counts.get(&executor.executor_id).cloned().unwrap_or_default();
+            let throughput = if task_summary.run_time_ms > 0 {
+                format_records_per_sec(task_summary.records_read as f64 * 1000.0 / task_summary.run_time_ms as f64)
+            } else {
+                "N/A".to_string()
+            };
+
             Row::new(vec![
-                Cell::from(executor.executor_id.clone()),
+                if imbalanced.contains(&executor.executor_id) {
+                    Cell::from(Span::styled(format!("{} ⚠", executor.executor_id), Style::default().fg(theme.status_warning)))
+                } else {
+                    Cell::from(executor.executor_id.clone())
+                },
                 Cell::from(executor.host.clone()),
                 Cell::from(Span::styled(status_text, status_style)),
                 Cell::from(executor.total_cores.to_string()),
                 Cell::from(memory_usage),
-                Cell::from(format!("{}/{}", executor.completed_tasks, executor.total_tasks)),
-                Cell::from(executor.failed_tasks.to_string()),
+                Cell::from(format!("{}/{}", task_summary.completed_tasks, task_summary.total_tasks)),
+                Cell::from(task_summary.failed_tasks.to_string()),
                 Cell::from(format!("{:.1}s", gc_time_sec)),
+                Cell::from(gc_overhead),
                 Cell::from(format_bytes(executor.total_input_bytes)),
                 Cell::from(format_bytes(executor.total_shuffle_read)),
                 Cell::from(format_bytes(executor.total_shuffle_write)),
+                Cell::from(throughput),
             ])
+            .style(row_style(i, zebra_stripe, theme))
         });
 
         let table = Table::new(
@@ -114,43 +244,152 @@ impl ExecutorsTab {
                 Constraint::Length(10), // Tasks
                 Constraint::Length(8),  // Failed
                 Constraint::Length(8),  // GC Time
+                Constraint::Length(6),  // GC%
                 Constraint::Length(10), // Input
                 Constraint::Length(12), // Shuffle Read
                 Constraint::Length(12), // Shuffle Write
+                Constraint::Length(12), // Throughput
             ]
         )
             .header(header)
-            .block(Block::default().borders(Borders::ALL).title("Executors"))
+            .block(Block::default().borders(Borders::ALL).title(filtered_title("Executors", failed_only)))
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
 
         f.render_stateful_widget(table, chunks[1], &mut table_state.clone());
     }
+
+    fn draw_memory_pressure(f: &mut Frame, area: ratatui::layout::Rect, executors: &[&Executor], theme: &Theme) {
+        let block = Block::default().borders(Borders::ALL).title("Memory Pressure (JVM Heap)");
+
+        if executors.is_empty() {
+            let paragraph = Paragraph::new("No live metrics available")
+                .block(block)
+                .style(Style::default().fg(theme.muted_fg));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let inner = block.inner(area);
+        f.render_widget(block, area);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(vec![Constraint::Length(1); executors.len()])
+            .split(inner);
+
+        for (executor, row) in executors.iter().zip(rows.iter()) {
+            let columns = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(12), Constraint::Min(0)])
+                .split(*row);
+
+            f.render_widget(Paragraph::new(executor.executor_id.clone()), columns[0]);
+
+            let sparkline = Sparkline::default()
+                .data(&executor.memory_samples)
+                .style(Style::default().fg(theme.accent));
+            f.render_widget(sparkline, columns[1]);
+        }
+    }
 }
 
-fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    
-    if bytes == 0 {
-        return "0 B".to_string();
+fn format_records_per_sec(rate: f64) -> String {
+    if rate >= 1_000_000.0 {
+        format!("{:.1}M rec/s", rate / 1_000_000.0)
+    } else if rate >= 1_000.0 {
+        format!("{:.1}K rec/s", rate / 1_000.0)
+    } else {
+        format!("{:.0} rec/s", rate)
     }
-    
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
-    
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
+}
+
+/// e.g. `#1 executor cores=4 memory=8192, task cpus=1 gpu=0.25`
+fn format_resource_profile(profile: &ResourceProfile) -> String {
+    let mut executor: Vec<_> = profile.executor_resources.iter().map(|(name, amount)| format!("{}={}", name, amount)).collect();
+    executor.sort();
+    let mut task: Vec<_> = profile.task_resources.iter().map(|(name, amount)| format!("{}={}", name, amount)).collect();
+    task.sort();
+    format!("#{} executor {}, task {}", profile.profile_id, executor.join(" "), task.join(" "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{BlockManager, SparkEventLogBuilder};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn flags_dynamic_allocation_in_summary() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_executor("1", "worker-a")
+            .add_stage(0, 1)
+            .add_task(0, 0, "1")
+            .build();
+        event_log.environment.spark_properties.insert("spark.dynamicAllocation.enabled".to_string(), "true".to_string());
+        let aggregates = event_log.precompute_aggregates();
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 20)).unwrap();
+        terminal
+            .draw(|f| ExecutorsTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), false, true, false, &Theme::default()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Dynamic Alloc: ON"));
+        assert!(rendered.contains("worker-a"));
     }
-    
-    if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
-    } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
+
+    #[test]
+    fn counts_tasks_per_executor_from_the_tasks() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_executor("1", "worker-a")
+            .add_stage(0, 3)
+            .add_task(0, 0, "1")
+            .add_task(1, 0, "1")
+            .add_task(2, 0, "1")
+            .build();
+        event_log.tasks.get_mut(&2).unwrap().status = crate::models::TaskStatus::Failed;
+        let aggregates = event_log.precompute_aggregates();
+
+        let mut terminal = Terminal::new(TestBackend::new(200, 20)).unwrap();
+        terminal
+            .draw(|f| ExecutorsTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), false, true, true, &Theme::default()))
+            .unwrap();
+
+        let rendered: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Total Tasks: 3 | Completed: 2 | Failed: 1"));
+        assert!(rendered.contains("2/3"));
+        assert!(rendered.contains("worker-a"));
+    }
+
+    #[test]
+    fn shows_driver_memory_in_summary() {
+        let mut event_log = SparkEventLogBuilder::new().add_executor("1", "worker-a").build();
+        event_log.driver_block_manager = Some(BlockManager {
+            executor_id: "driver".to_string(),
+            host: "driver-host".to_string(),
+            port: 7078,
+            max_memory: 4 * 1024 * 1024 * 1024,
+            max_on_heap_memory: 4 * 1024 * 1024 * 1024,
+            max_off_heap_memory: 0,
+        });
+        let aggregates = event_log.precompute_aggregates();
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 20)).unwrap();
+        terminal
+            .draw(|f| ExecutorsTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), false, true, false, &Theme::default()))
+            .unwrap();
+
+        let rendered: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("Driver Memory: 4.0 GB on-heap"));
+        assert!(!rendered.contains("Imbalanced"));
+        assert!(!rendered.contains("off-heap"));
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,91 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use super::{format_duration, row_style};
+use crate::config::Theme;
+use crate::parser::LogFileEntry;
+
+pub struct FilePicker;
+
+impl FilePicker {
+    pub fn draw(
+        f: &mut Frame,
+        entries: &[LogFileEntry],
+        table_state: &TableState,
+        theme: &Theme,
+    ) {
+        f.render_widget(Block::default().style(Style::default().fg(theme.text_fg).bg(theme.background)), f.area());
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3)])
+            .split(f.area());
+
+        let header_cells = ["File", "Application", "App ID", "Jobs", "Duration"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
+
+        let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+        let rows = entries.iter().enumerate().map(|(i, entry)| {
+            let file_name = entry.path.file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| entry.path.display().to_string());
+
+            match &entry.result {
+                Ok(summary) => {
+                    let duration = summary.duration_ms
+                        .map(format_duration)
+                        .unwrap_or_else(|| "Running".to_string());
+
+                    Row::new(vec![
+                        Cell::from(file_name),
+                        Cell::from(summary.app_name.clone()),
+                        Cell::from(summary.app_id.clone()),
+                        Cell::from(summary.job_count.to_string()),
+                        Cell::from(duration),
+                    ])
+                    .style(row_style(i, true, theme))
+                }
+                Err(error) => Row::new(vec![
+                    Cell::from(file_name),
+                    Cell::from(format!("⚠ Skipped: {}", error)),
+                ])
+                .style(row_style(i, true, theme).fg(theme.status_warning)),
+            }
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(30), // File
+                Constraint::Min(20),    // Application
+                Constraint::Length(30), // App ID
+                Constraint::Length(6),  // Jobs
+                Constraint::Length(12), // Duration
+            ]
+        )
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(format!("Event Logs ({})", entries.len())))
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(table, chunks[0], &mut table_state.clone());
+
+        let help = Paragraph::new("↑↓/jk (Navigate) | Enter (Open log) | q/Esc (Quit)")
+            .style(Style::default().fg(theme.muted_fg))
+            .block(Block::default().borders(Borders::ALL).title("Help"));
+
+        f.render_widget(help, chunks[1]);
+    }
+}
@@ -0,0 +1,58 @@
+use chrono_tz::Tz;
+use ratatui::{
+    style::{Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{centered_rect, detail_line, format_duration, format_time, section_line};
+use crate::config::Theme;
+use crate::models::Job;
+
+pub struct JobDetailPopup;
+
+impl JobDetailPopup {
+    pub fn draw(f: &mut Frame, area: ratatui::layout::Rect, job: &Job, sql_execution_id: Option<u64>, timezone: Tz, theme: &Theme) {
+        let popup_area = centered_rect(70, 70, area);
+        f.render_widget(Clear, popup_area);
+
+        let duration = if let Some(completion_time) = job.completion_time {
+            format_duration((completion_time - job.submission_time).num_milliseconds().max(0) as u64)
+        } else {
+            "Running".to_string()
+        };
+
+        let stage_ids: Vec<_> = job.stage_ids.iter().map(|id| id.to_string()).collect();
+
+        let mut lines = vec![
+            detail_line("Job ID", job.job_id.to_string(), theme),
+            detail_line("Description", job.description.clone().unwrap_or_else(|| job.name.clone()), theme),
+            detail_line("Status", job.status.to_string(), theme),
+            detail_line("Submission Time", format_time(job.submission_time, "%Y-%m-%d %H:%M:%S", timezone), theme),
+            detail_line("Duration", duration, theme),
+            detail_line("Stages", stage_ids.join(", "), theme),
+            detail_line("SQL", sql_execution_id.map(|id| format!("#{} (s to open)", id)).unwrap_or_else(|| "N/A".to_string()), theme),
+        ];
+        if let Some(reason) = &job.failure_reason {
+            lines.push(Line::from(vec![
+                Span::styled("Failure Reason: ", Style::default().fg(theme.status_failed)),
+                Span::styled(reason.clone(), Style::default().fg(theme.status_failed)),
+            ]));
+        }
+        lines.push(Line::from(""));
+        lines.push(section_line("Call Site", theme));
+
+        match job.properties.get("callSite.long") {
+            Some(call_site) => lines.extend(call_site.lines().map(|line| Line::from(line.to_string()))),
+            None => lines.push(Line::from(Span::styled("No call site recorded", Style::default().fg(theme.muted_fg)))),
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!("Job {} - Enter/Esc to close", job.job_id)))
+            .style(Style::default().fg(theme.text_fg).bg(theme.background))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
@@ -1,21 +1,46 @@
+use chrono_tz::Tz;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::Span,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
-use crate::models::{JobStatus, SparkEventLog};
+use super::{filtered_title, format_duration, format_time, row_style};
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, Job, JobStatus, SparkEventLog};
+
+const HEADERS: [&str; 7] = ["Job ID", "Description", "Status", "Submission Time", "Duration", "Stages", "Tasks"];
+const DESCRIPTION_COLUMN: usize = 1;
+/// Widest any column is allowed to grow to fit its content.
+const MAX_COLUMN_WIDTH: usize = 60;
+/// Longest SQL description shown under the summary for the selected job.
+const MAX_SQL_CONTEXT_CHARS: usize = 80;
 
 pub struct JobsTab;
 
 impl JobsTab {
+    /// Jobs in table order, so a selected row index maps back to its job.
+    pub fn sorted_jobs(event_log: &SparkEventLog, failed_only: bool) -> Vec<&Job> {
+        let mut jobs: Vec<_> = event_log.jobs.values()
+            .filter(|j| !failed_only || matches!(j.status, JobStatus::Failed))
+            .collect();
+        jobs.sort_by_key(|job| job.job_id);
+        jobs
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
         table_state: &TableState,
+        zebra_stripe: bool,
+        failed_only: bool,
+        timezone: Tz,
+        theme: &Theme,
     ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -25,82 +50,122 @@ impl JobsTab {
         // Summary section
         let summary_text = format!(
             "Total Jobs: {} | Active: {} | Completed: {} | Failed: {}",
-            event_log.jobs.len(),
-            event_log.jobs.values().filter(|j| matches!(j.status, JobStatus::Running)).count(),
-            event_log.jobs.values().filter(|j| matches!(j.status, JobStatus::Succeeded)).count(),
-            event_log.jobs.values().filter(|j| matches!(j.status, JobStatus::Failed)).count(),
+            aggregates.app_totals.total_jobs,
+            aggregates.job_status_counts.running,
+            aggregates.job_status_counts.succeeded,
+            aggregates.job_status_counts.failed,
         );
 
-        let summary = ratatui::widgets::Paragraph::new(summary_text)
+        let mut summary_lines = vec![Line::from(summary_text)];
+
+        // SQL context of the selected job, so the query is visible without switching tabs
+        let jobs = Self::sorted_jobs(event_log, failed_only);
+        let selected_execution = table_state.selected()
+            .and_then(|i| jobs.get(i))
+            .and_then(|job| event_log.sql_execution_for_job(job.job_id));
+        if let Some(execution) = selected_execution {
+            let description = if execution.description.chars().count() > MAX_SQL_CONTEXT_CHARS {
+                let truncated: String = execution.description.chars().take(MAX_SQL_CONTEXT_CHARS - 3).collect();
+                format!("{}...", truncated)
+            } else {
+                execution.description.clone()
+            };
+            summary_lines.push(Line::from(Span::styled(format!("SQL: {}", description), Style::default().fg(theme.muted_fg))));
+        }
+
+        let summary = ratatui::widgets::Paragraph::new(summary_lines)
             .block(Block::default().borders(Borders::ALL).title("Jobs Summary"))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text_fg));
 
         f.render_widget(summary, chunks[0]);
 
         // Jobs table
-        let header_cells = ["Job ID", "Description", "Status", "Submission Time", "Duration", "Stages", "Tasks"]
+        let header_cells = HEADERS
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-        let mut jobs: Vec<_> = event_log.jobs.values().collect();
-        jobs.sort_by_key(|job| job.job_id);
-
-        let rows = jobs.iter().map(|job| {
-            let duration = if let Some(completion_time) = job.completion_time {
-                format!("{}ms", (completion_time - job.submission_time).num_milliseconds())
-            } else {
-                "Running".to_string()
-            };
-
+        let rows = jobs.iter().enumerate().map(|(i, job)| {
             let status_style = match job.status {
-                JobStatus::Running => Style::default().fg(Color::Blue),
-                JobStatus::Succeeded => Style::default().fg(Color::Green),
-                JobStatus::Failed => Style::default().fg(Color::Red),
-                JobStatus::Unknown => Style::default().fg(Color::Gray),
-            };
-
-            let status_text = match job.status {
-                JobStatus::Running => "RUNNING",
-                JobStatus::Succeeded => "SUCCEEDED",
-                JobStatus::Failed => "FAILED",
-                JobStatus::Unknown => "UNKNOWN",
+                JobStatus::Running => Style::default().fg(theme.status_running),
+                JobStatus::Succeeded => Style::default().fg(theme.status_success),
+                JobStatus::Failed => Style::default().fg(theme.status_failed),
+                JobStatus::Unknown => Style::default().fg(theme.muted_fg),
             };
 
             Row::new(vec![
                 Cell::from(job.job_id.to_string()),
                 Cell::from(job.description.as_deref().unwrap_or(&job.name)),
-                Cell::from(Span::styled(status_text, status_style)),
-                Cell::from(job.submission_time.format("%Y-%m-%d %H:%M:%S").to_string()),
-                Cell::from(duration),
+                Cell::from(Span::styled(job.status.to_string(), status_style)),
+                Cell::from(format_time(job.submission_time, "%Y-%m-%d %H:%M:%S", timezone)),
+                Cell::from(duration_text(job)),
                 Cell::from(job.stage_ids.len().to_string()),
                 Cell::from(format!("{}/{}", job.num_completed_tasks, job.num_tasks)),
             ])
+            .style(row_style(i, zebra_stripe, theme))
         });
 
-        let table = Table::new(
-            rows,
-            [
-                Constraint::Length(8),  // Job ID
-                Constraint::Min(20),    // Description
-                Constraint::Length(10), // Status
-                Constraint::Length(19), // Submission Time
-                Constraint::Length(12), // Duration
-                Constraint::Length(8),  // Stages
-                Constraint::Length(12), // Tasks
-            ]
-        )
+        let widths = Self::compute_column_widths(&jobs, chunks[1].width, timezone);
+        let table = Table::new(rows, widths)
             .header(header)
-            .block(Block::default().borders(Borders::ALL).title("Jobs"))
+            .block(Block::default().borders(Borders::ALL).title(filtered_title("Jobs", failed_only)))
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
 
         f.render_stateful_widget(table, chunks[1], &mut table_state.clone());
     }
+
+    /// Size each column to its widest cell (header included), capped at
+    /// `MAX_COLUMN_WIDTH`, and give whatever is left to Description.
+    fn compute_column_widths(rows: &[&Job], terminal_width: u16, timezone: Tz) -> Vec<Constraint> {
+        let mut widths: Vec<usize> = HEADERS.iter().map(|h| h.chars().count()).collect();
+
+        for job in rows {
+            let cells = [
+                job.job_id.to_string(),
+                job.description.as_deref().unwrap_or(&job.name).to_string(),
+                job.status.to_string(),
+                format_time(job.submission_time, "%Y-%m-%d %H:%M:%S", timezone),
+                duration_text(job),
+                job.stage_ids.len().to_string(),
+                format!("{}/{}", job.num_completed_tasks, job.num_tasks),
+            ];
+            for (width, cell) in widths.iter_mut().zip(cells.iter()) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let widths: Vec<u16> = widths.iter().map(|w| (*w).min(MAX_COLUMN_WIDTH) as u16).collect();
+
+        // Borders, highlight symbol and one space between each column
+        let chrome = 2 + 3 + (widths.len() as u16 - 1);
+        let fixed: u16 = widths.iter().enumerate()
+            .filter(|(i, _)| *i != DESCRIPTION_COLUMN)
+            .map(|(_, w)| *w)
+            .sum();
+        let remaining = terminal_width.saturating_sub(chrome + fixed);
+
+        widths.iter().enumerate().map(|(i, w)| {
+            if i == DESCRIPTION_COLUMN {
+                Constraint::Min(remaining)
+            } else {
+                Constraint::Length(*w)
+            }
+        }).collect()
+    }
+}
+
+fn duration_text(job: &Job) -> String {
+    if let Some(completion_time) = job.completion_time {
+        format_duration((completion_time - job.submission_time).num_milliseconds().max(0) as u64)
+    } else {
+        "Running".to_string()
+    }
 }
\ No newline at end of file
@@ -0,0 +1,38 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::Style,
+    widgets::{Block, Borders, Gauge},
+    Frame,
+};
+use std::path::Path;
+
+use super::{centered_rect, format_bytes};
+use crate::config::Theme;
+
+pub struct LoadingScreen;
+
+impl LoadingScreen {
+    pub fn draw(f: &mut Frame, log_path: &Path, bytes_read: u64, total_bytes: u64, theme: &Theme) {
+        f.render_widget(Block::default().style(Style::default().fg(theme.text_fg).bg(theme.background)), f.area());
+
+        let area = centered_rect(60, 100, f.area());
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(3), Constraint::Min(0)])
+            .split(area);
+
+        let ratio = if total_bytes > 0 {
+            (bytes_read as f64 / total_bytes as f64).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title(format!("Loading {}", log_path.display())))
+            .gauge_style(Style::default().fg(theme.status_running).bg(theme.background))
+            .ratio(ratio)
+            .label(format!("{} / {} ({:.0}%)", format_bytes(bytes_read), format_bytes(total_bytes), ratio * 100.0));
+
+        f.render_widget(gauge, chunks[1]);
+    }
+}
@@ -4,10 +4,163 @@ pub mod tasks;
 pub mod executors;
 pub mod sql;
 pub mod environment;
+pub mod summary;
+pub mod file_picker;
+pub mod loading;
+pub mod parse_warnings;
+pub mod task_detail;
+pub mod job_detail;
+pub mod stage_detail;
+pub mod executor_detail;
+pub mod sql_detail;
+pub mod executor_timeline;
+pub mod error_popup;
 
 pub use jobs::JobsTab;
 pub use stages::StagesTab;
 pub use tasks::TasksTab;
 pub use executors::ExecutorsTab;
 pub use sql::SqlTab;
-pub use environment::EnvironmentTab;
\ No newline at end of file
+pub use environment::EnvironmentTab;
+pub use summary::SummaryTab;
+pub use file_picker::FilePicker;
+pub use loading::LoadingScreen;
+pub use parse_warnings::ParseWarningsPopup;
+pub use task_detail::TaskDetailPopup;
+pub use job_detail::JobDetailPopup;
+pub use stage_detail::StageDetailPopup;
+pub use executor_detail::ExecutorDetailPopup;
+pub use sql_detail::SqlDetailPopup;
+pub use executor_timeline::ExecutorTimeline;
+pub use error_popup::ErrorPopup;
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+};
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+
+use crate::config::Theme;
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
+    
+    if bytes == 0 {
+        return "0 B".to_string();
+    }
+    
+    let mut size = bytes as f64;
+    let mut unit_index = 0;
+    
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+    
+    if unit_index == 0 {
+        format!("{} {}", bytes, UNITS[unit_index])
+    } else {
+        format!("{:.1} {}", size, UNITS[unit_index])
+    }
+}
+
+/// Throughput of `bytes` moved in `ms` milliseconds, e.g. `234.5 MB/s`.
+pub fn format_bytes_per_sec(bytes: u64, ms: u64) -> String {
+    if ms == 0 {
+        return "N/A".to_string();
+    }
+
+    let per_sec = (bytes as f64 * 1000.0 / ms as f64) as u64;
+    format!("{}/s", format_bytes(per_sec))
+}
+
+/// Format a count with thousands separators, e.g. `1,234,567`.
+pub fn format_count(count: u64) -> String {
+    let digits = count.to_string();
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3);
+
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(',');
+        }
+        formatted.push(c);
+    }
+
+    formatted
+}
+
+/// Format a duration in milliseconds as `12.3s`, `3m 45s` or `2h 15m`.
+pub fn format_duration(ms: u64) -> String {
+    let secs = ms / 1000;
+
+    if secs < 60 {
+        format!("{:.1}s", ms as f64 / 1000.0)
+    } else if secs < 3600 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// Format an event log timestamp with `format`, in the display timezone.
+pub fn format_time(time: DateTime<Utc>, format: &str, timezone: Tz) -> String {
+    time.with_timezone(&timezone).format(format).to_string()
+}
+
+/// Table title, marked when the failed-only filter is on.
+pub fn filtered_title(title: &str, failed_only: bool) -> String {
+    if failed_only {
+        format!("{} (failed only)", title)
+    } else {
+        title.to_string()
+    }
+}
+
+/// Background for the `index`th table row: even rows are shaded when `zebra_stripe` is on.
+pub fn row_style(index: usize, zebra_stripe: bool, theme: &Theme) -> Style {
+    if zebra_stripe && index.is_multiple_of(2) {
+        Style::default().bg(theme.zebra_stripe_bg)
+    } else {
+        Style::default()
+    }
+}
+
+/// Rect of the given percentage size centered within `area`, for popups.
+pub fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Percentage((100 - percent_y) / 2),
+            Constraint::Percentage(percent_y),
+            Constraint::Percentage((100 - percent_y) / 2),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
+
+/// Bold section heading inside a detail popup.
+pub fn section_line(title: &str, theme: &Theme) -> Line<'static> {
+    Line::from(Span::styled(
+        title.to_string(),
+        Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// `label: value` line inside a detail popup.
+pub fn detail_line(label: &str, value: String, theme: &Theme) -> Line<'static> {
+    Line::from(vec![
+        Span::styled(format!("{}: ", label), Style::default().fg(theme.label_fg)),
+        Span::raw(value),
+    ])
+}
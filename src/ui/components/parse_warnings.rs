@@ -0,0 +1,73 @@
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    widgets::{Block, Borders, Cell, Clear, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+use super::{centered_rect, row_style};
+use crate::config::Theme;
+use crate::models::ParseWarning;
+
+pub struct ParseWarningsPopup;
+
+impl ParseWarningsPopup {
+    pub fn draw(
+        f: &mut Frame,
+        area: ratatui::layout::Rect,
+        warnings: &[ParseWarning],
+        table_state: &TableState,
+        zebra_stripe: bool,
+        theme: &Theme,
+    ) {
+        let popup_area = centered_rect(80, 70, area);
+        f.render_widget(Clear, popup_area);
+        f.render_widget(Block::default().style(Style::default().fg(theme.text_fg).bg(theme.background)), popup_area);
+
+        let title = format!("Parse Warnings ({}) - w/Esc to close", warnings.len());
+
+        if warnings.is_empty() {
+            let paragraph = Paragraph::new("No parse warnings")
+                .block(Block::default().borders(Borders::ALL).title(title))
+                .style(Style::default().fg(theme.status_success));
+            f.render_widget(paragraph, popup_area);
+            return;
+        }
+
+        let header_cells = ["Line", "Content", "Error"]
+            .iter()
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
+
+        let header = Row::new(header_cells).height(1).bottom_margin(1);
+
+        let rows = warnings.iter().enumerate().map(|(i, warning)| {
+            Row::new(vec![
+                Cell::from(warning.line_number.to_string()),
+                Cell::from(warning.content.clone()),
+                Cell::from(warning.error.clone()).style(Style::default().fg(theme.status_failed)),
+            ])
+            .style(row_style(i, zebra_stripe, theme))
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),      // Line
+                Constraint::Percentage(50), // Content
+                Constraint::Min(20),        // Error
+            ]
+        )
+            .header(header)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .column_spacing(1)
+            .highlight_style(
+                Style::default()
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol(">> ");
+
+        f.render_stateful_widget(table, popup_area, &mut table_state.clone());
+    }
+}
@@ -1,21 +1,67 @@
+use chrono_tz::Tz;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::Span,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState, Paragraph, Wrap},
     Frame,
 };
 
-use crate::models::{SqlExecutionStatus, SparkEventLog};
+use super::{format_duration, format_time, row_style};
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, SqlExecution, SqlExecutionStatus, SparkEventLog};
+
+/// Characters of the logical plan shown in the "Plan" column.
+const MAX_PLAN_CHARS: usize = 60;
 
 pub struct SqlTab;
 
 impl SqlTab {
+    /// Executions in table order, so a selected row index maps back to its execution.
+    pub fn sorted_executions(event_log: &SparkEventLog) -> Vec<&SqlExecution> {
+        let mut executions: Vec<_> = event_log.sql_executions.values().collect();
+        executions.sort_by_key(|execution| execution.execution_id);
+        executions
+    }
+
+    /// The execution's status, followed by badges for what AQE did to it and
+    /// how many operators the physical plan lists.
+    fn status_line(execution: &SqlExecution, theme: &Theme) -> Line<'static> {
+        let status_style = match execution.status {
+            SqlExecutionStatus::Running => Style::default().fg(theme.status_running),
+            SqlExecutionStatus::Completed => Style::default().fg(theme.status_success),
+            SqlExecutionStatus::Failed => Style::default().fg(theme.status_failed),
+        };
+
+        let mut status_spans = vec![Span::styled(execution.status.to_string(), status_style)];
+        if execution.has_skew_join {
+            status_spans.push(Span::styled(" ⚠ Skew Join Detected", Style::default().fg(theme.status_warning)));
+        }
+        if execution.aqe_broadcast_joins_added > 0 {
+            status_spans.push(Span::styled(
+                format!(" AQE: +{} broadcast joins", execution.aqe_broadcast_joins_added),
+                Style::default().fg(theme.status_success),
+            ));
+        }
+        if !execution.plan_operators.is_empty() {
+            status_spans.push(Span::styled(
+                format!(" [{} ops]", execution.plan_operators.len()),
+                Style::default().fg(theme.muted_fg),
+            ));
+        }
+        Line::from(status_spans)
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
         table_state: &TableState,
+        zebra_stripe: bool,
+        timezone: Tz,
+        theme: &Theme,
     ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -46,9 +92,28 @@ impl SqlTab {
             "No SQL executions found in this application".to_string()
         };
 
-        let summary = Paragraph::new(summary_text)
+        let partitions = &aggregates.shuffle_partitions;
+        let mut partition_spans = vec![Span::raw(format!(
+            "Shuffle Partitions: {} configured | {} observed",
+            partitions.configured,
+            partitions.observed.map(|n| n.to_string()).unwrap_or_else(|| "N/A".to_string()),
+        ))];
+        if partitions.aqe_enabled {
+            let coalesced = partitions.aqe_coalesced
+                .map(|n| format!(" (coalesced to {})", n))
+                .unwrap_or_default();
+            partition_spans.push(Span::raw(format!(" | AQE: enabled{}", coalesced)));
+        }
+        if partitions.is_oversized() {
+            partition_spans.push(Span::styled(
+                " | ⚠ shuffle.partitions far exceeds the observed parallelism",
+                Style::default().fg(theme.status_warning),
+            ));
+        }
+
+        let summary = Paragraph::new(vec![Line::from(summary_text), Line::from(partition_spans)])
             .block(Block::default().borders(Borders::ALL).title("SQL Summary"))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text_fg));
 
         f.render_widget(summary, chunks[0]);
 
@@ -63,7 +128,7 @@ impl SqlTab {
                 • SQL metrics and performance data"
             )
                 .block(Block::default().borders(Borders::ALL).title("SQL Executions"))
-                .style(Style::default().fg(Color::Gray))
+                .style(Style::default().fg(theme.muted_fg))
                 .wrap(Wrap { trim: true });
 
             f.render_widget(no_sql_message, chunks[1]);
@@ -73,60 +138,54 @@ impl SqlTab {
         // SQL executions table
         let header_cells = [
             "Execution ID", "Description", "Status", "Submission Time", 
-            "Duration", "Jobs", "Stages", "Details"
+            "Duration", "Jobs", "Stages", "Plan"
         ]
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-        let mut executions: Vec<_> = event_log.sql_executions.values().collect();
-        executions.sort_by_key(|execution| execution.execution_id);
+        let executions = Self::sorted_executions(event_log);
+        let status_lines: Vec<Line> = executions.iter().map(|execution| Self::status_line(execution, theme)).collect();
+        // Widen the column only as far as the longest status with its badges
+        let status_width = status_lines.iter().map(Line::width).max().unwrap_or(0).max(10) as u16;
 
-        let rows = executions.iter().map(|execution| {
+        let rows = executions.iter().zip(status_lines).enumerate().map(|(i, (execution, status_line))| {
             let duration = if let Some(completion_time) = execution.completion_time {
-                format!("{}ms", (completion_time - execution.submission_time).num_milliseconds())
+                format_duration((completion_time - execution.submission_time).num_milliseconds().max(0) as u64)
             } else {
                 "Running".to_string()
             };
 
-            let status_style = match execution.status {
-                SqlExecutionStatus::Running => Style::default().fg(Color::Blue),
-                SqlExecutionStatus::Completed => Style::default().fg(Color::Green),
-                SqlExecutionStatus::Failed => Style::default().fg(Color::Red),
-            };
-
-            let status_text = match execution.status {
-                SqlExecutionStatus::Running => "RUNNING",
-                SqlExecutionStatus::Completed => "COMPLETED",
-                SqlExecutionStatus::Failed => "FAILED",
-            };
-
-            // Truncate description and details for display
+            // Truncate description and plan for display
             let display_description = if execution.description.len() > 40 {
                 format!("{}...", &execution.description[..37])
             } else {
                 execution.description.clone()
             };
 
-            let display_details = if execution.details.len() > 30 {
-                format!("{}...", &execution.details[..27])
-            } else if execution.details.is_empty() {
+            // The plan spans many lines; show its start on one
+            let plan = execution.plan_description.split_whitespace().collect::<Vec<_>>().join(" ");
+            let display_plan = if plan.chars().count() > MAX_PLAN_CHARS {
+                let truncated: String = plan.chars().take(MAX_PLAN_CHARS - 3).collect();
+                format!("{}...", truncated)
+            } else if plan.is_empty() {
                 "N/A".to_string()
             } else {
-                execution.details.clone()
+                plan
             };
 
             Row::new(vec![
                 Cell::from(execution.execution_id.to_string()),
                 Cell::from(display_description),
-                Cell::from(Span::styled(status_text, status_style)),
-                Cell::from(execution.submission_time.format("%H:%M:%S").to_string()),
+                Cell::from(status_line),
+                Cell::from(format_time(execution.submission_time, "%H:%M:%S", timezone)),
                 Cell::from(duration),
                 Cell::from(execution.jobs.len().to_string()),
                 Cell::from(execution.stages.len().to_string()),
-                Cell::from(display_details),
+                Cell::from(display_plan),
             ])
+            .style(row_style(i, zebra_stripe, theme))
         });
 
         let table = Table::new(
@@ -134,12 +193,12 @@ impl SqlTab {
             [
                 Constraint::Length(12), // Execution ID
                 Constraint::Min(30),    // Description
-                Constraint::Length(10), // Status
+                Constraint::Length(status_width), // Status
                 Constraint::Length(12), // Submission Time
                 Constraint::Length(10), // Duration
                 Constraint::Length(6),  // Jobs
                 Constraint::Length(7),  // Stages
-                Constraint::Min(20),    // Details
+                Constraint::Min(20),    // Plan
             ]
         )
             .header(header)
@@ -147,11 +206,84 @@ impl SqlTab {
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
 
         f.render_stateful_widget(table, chunks[1], &mut table_state.clone());
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SparkEventLogBuilder;
+    use chrono::{DateTime, Utc};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn draws_sql_execution_row() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_sql_execution(0, "select count(*) from events", &[0])
+            .build();
+        let execution = event_log.sql_executions.get_mut(&0).unwrap();
+        execution.stages = vec![0, 1];
+        execution.plan_description = "== Parsed Logical Plan ==\n'Aggregate [count(1)]\n+- 'UnresolvedRelation [events]".to_string();
+
+        let aggregates = event_log.precompute_aggregates();
+        let mut terminal = Terminal::new(TestBackend::new(200, 20)).unwrap();
+        terminal
+            .draw(|f| SqlTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), true, Tz::UTC, &Theme::default()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let rendered: String = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("select count(*) from events"));
+        assert!(rendered.contains("SQL Executions: 1 | Completed: 1"));
+        assert!(rendered.contains("== Parsed Logical Plan == 'Aggregate [count(1)] +- 'Unres..."));
+        assert!(!rendered.contains("Skew Join"));
+    }
+
+    #[test]
+    fn flags_aqe_changes_in_status_column() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_sql_execution(0, "select * from a join b", &[])
+            .build();
+        let execution = event_log.sql_executions.get_mut(&0).unwrap();
+        execution.has_skew_join = true;
+        execution.aqe_broadcast_joins_added = 2;
+        execution.plan_operators = vec!["Scan parquet default.a".to_string(), "Project".to_string()];
+
+        let aggregates = event_log.precompute_aggregates();
+        let mut terminal = Terminal::new(TestBackend::new(200, 20)).unwrap();
+        terminal
+            .draw(|f| SqlTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), true, Tz::UTC, &Theme::default()))
+            .unwrap();
+
+        let rendered: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(rendered.contains("COMPLETED ⚠ Skew Join Detected AQE: +2 broadcast joins [2 ops]"));
+    }
+
+    #[test]
+    fn shows_submission_time_in_display_timezone() {
+        let mut event_log = SparkEventLogBuilder::new()
+            .add_sql_execution(0, "select 1", &[])
+            .build();
+        let execution = event_log.sql_executions.get_mut(&0).unwrap();
+        execution.submission_time = DateTime::parse_from_rfc3339("2024-12-01T12:00:00Z").unwrap().with_timezone(&Utc);
+
+        let aggregates = event_log.precompute_aggregates();
+        let render = |timezone: Tz| {
+            let mut terminal = Terminal::new(TestBackend::new(160, 20)).unwrap();
+            terminal
+                .draw(|f| SqlTab::draw(f, f.area(), &event_log, &aggregates, &TableState::default(), true, timezone, &Theme::default()))
+                .unwrap();
+            terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect::<String>()
+        };
+
+        assert!(render(Tz::UTC).contains("12:00:00"));
+        assert!(render(chrono_tz::America::New_York).contains("07:00:00"));
+    }
+}
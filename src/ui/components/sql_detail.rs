@@ -0,0 +1,157 @@
+use chrono_tz::Tz;
+use ratatui::{
+    layout::Rect,
+    style::{Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{centered_rect, detail_line, format_bytes, format_duration, format_time, section_line};
+use crate::config::Theme;
+use crate::models::{SqlExecution, SqlMetric};
+
+pub struct SqlDetailPopup;
+
+impl SqlDetailPopup {
+    /// Where the popup is drawn within `area`.
+    pub fn popup_area(area: Rect) -> Rect {
+        centered_rect(70, 70, area)
+    }
+
+    /// Lines the popup body scrolls by for a page, inside its borders.
+    pub fn page_height(area: Rect) -> u16 {
+        Self::popup_area(area).height.saturating_sub(2)
+    }
+
+    /// Largest useful scroll offset: the one that puts the last line at the bottom.
+    pub fn max_scroll(execution: &SqlExecution, area: Rect, show_initial_plan: bool, timezone: Tz, theme: &Theme) -> u16 {
+        let popup_area = Self::popup_area(area);
+        let inner_width = popup_area.width.saturating_sub(2).max(1) as usize;
+        let wrapped_lines: usize = Self::lines(execution, show_initial_plan, timezone, theme)
+            .iter()
+            .map(|line| line.width().div_ceil(inner_width).max(1))
+            .sum();
+        (wrapped_lines as u16).saturating_sub(Self::page_height(area))
+    }
+
+    pub fn draw(f: &mut Frame, area: Rect, execution: &SqlExecution, scroll: u16, show_initial_plan: bool, timezone: Tz, theme: &Theme) {
+        let popup_area = Self::popup_area(area);
+        f.render_widget(Clear, popup_area);
+
+        let paragraph = Paragraph::new(Self::lines(execution, show_initial_plan, timezone, theme))
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "SQL Execution {} - ↑↓/PgUp/PgDn/g/G to scroll, p initial/final plan, Enter/Esc to close",
+                execution.execution_id
+            )))
+            .style(Style::default().fg(theme.text_fg).bg(theme.background))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll.min(Self::max_scroll(execution, area, show_initial_plan, timezone, theme)), 0));
+
+        f.render_widget(paragraph, popup_area);
+    }
+
+    fn lines(execution: &SqlExecution, show_initial_plan: bool, timezone: Tz, theme: &Theme) -> Vec<Line<'static>> {
+        let duration = if let Some(completion_time) = execution.completion_time {
+            format_duration((completion_time - execution.submission_time).num_milliseconds().max(0) as u64)
+        } else {
+            "Running".to_string()
+        };
+
+        let mut lines = vec![
+            detail_line("Execution ID", execution.execution_id.to_string(), theme),
+            detail_line("Run ID", execution.run_id.clone().unwrap_or_else(|| "N/A".to_string()), theme),
+            detail_line("Description", execution.description.clone(), theme),
+            detail_line("Status", execution.status.to_string(), theme),
+            detail_line("Submission Time", format_time(execution.submission_time, "%Y-%m-%d %H:%M:%S", timezone), theme),
+            detail_line("Duration", duration, theme),
+        ];
+
+        if let Some(coalesced) = execution.aqe_coalesced_partitions {
+            let initial = execution.aqe_initial_partitions
+                .map(|count| count.to_string())
+                .unwrap_or_else(|| "?".to_string());
+            lines.push(detail_line("AQE Coalesced", format!("{} → {} partitions", initial, coalesced), theme));
+        }
+
+        if let Some(error) = &execution.error_message {
+            // Messages often carry a stack trace; keep its lines apart
+            let error_style = Style::default().fg(theme.status_failed);
+            let mut error_lines = error.lines();
+            lines.push(Line::from(vec![
+                Span::styled("Error: ", error_style),
+                Span::styled(error_lines.next().unwrap_or_default().to_string(), error_style),
+            ]));
+            lines.extend(error_lines.map(|line| Line::from(Span::styled(line.to_string(), error_style))));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section_line("SQL Metrics", theme));
+        if execution.metrics.is_empty() {
+            lines.push(Line::from(Span::styled("No SQL metric values recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            for metric in &execution.metrics {
+                lines.push(detail_line(&metric.name, format_sql_metric(metric), theme));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section_line("Modified Configs", theme));
+
+        if execution.modified_configs.is_empty() {
+            lines.push(Line::from(Span::styled("No modified configs recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            let mut configs: Vec<_> = execution.modified_configs.iter().collect();
+            configs.sort_by_key(|(key, _)| key.as_str());
+            for (key, value) in configs {
+                lines.push(detail_line(key, value.clone(), theme));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section_line("AQE Metric Updates", theme));
+        if execution.aqe_metric_updates.is_empty() {
+            lines.push(Line::from(Span::styled("No AQE metric updates recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            for (name, value) in &execution.aqe_metric_updates {
+                lines.push(detail_line(name, value.to_string(), theme));
+            }
+        }
+
+        if !execution.plan_description.is_empty() {
+            lines.push(Line::from(""));
+            lines.push(section_line("Logical Plan", theme));
+            lines.extend(execution.plan_description.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        let (title, plan) = if show_initial_plan {
+            ("Initial Plan", &execution.initial_physical_plan)
+        } else {
+            ("Final Plan", &execution.physical_plan)
+        };
+        let mut plan_header = section_line(title, theme);
+        if execution.initial_physical_plan != execution.physical_plan {
+            plan_header.push_span(Span::styled(" (AQE modified)", Style::default().fg(theme.status_running)));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(plan_header);
+        if plan.is_empty() {
+            lines.push(Line::from(Span::styled("No physical plan recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            lines.extend(plan.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        lines
+    }
+}
+
+/// A metric value in the unit its type implies.
+fn format_sql_metric(metric: &SqlMetric) -> String {
+    match metric.metric_type.as_str() {
+        "size" => format_bytes(metric.value),
+        "timing" => format_duration(metric.value),
+        "nsTiming" => format_duration(metric.value / 1_000_000),
+        _ => metric.value.to_string(),
+    }
+}
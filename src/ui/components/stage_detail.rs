@@ -0,0 +1,133 @@
+use ratatui::{
+    layout::Rect,
+    style::{Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{centered_rect, detail_line, format_bytes, format_duration, section_line};
+use crate::config::Theme;
+use crate::models::Stage;
+
+const TASK_BINARY_WARN_BYTES: u64 = 1000 * 1024;
+
+pub struct StageDetailPopup;
+
+impl StageDetailPopup {
+    /// Where the popup is drawn within `area`.
+    pub fn popup_area(area: Rect) -> Rect {
+        centered_rect(70, 70, area)
+    }
+
+    /// Lines the popup body scrolls by for a page, inside its borders.
+    pub fn page_height(area: Rect) -> u16 {
+        Self::popup_area(area).height.saturating_sub(2)
+    }
+
+    /// Largest useful scroll offset: the one that puts the last line at the bottom.
+    pub fn max_scroll(stage: &Stage, area: Rect, theme: &Theme) -> u16 {
+        let popup_area = Self::popup_area(area);
+        let inner_width = popup_area.width.saturating_sub(2).max(1) as usize;
+        let wrapped_lines: usize = Self::lines(stage, theme)
+            .iter()
+            .map(|line| line.width().div_ceil(inner_width).max(1))
+            .sum();
+        (wrapped_lines as u16).saturating_sub(Self::page_height(area))
+    }
+
+    pub fn draw(f: &mut Frame, area: Rect, stage: &Stage, scroll: u16, theme: &Theme) {
+        let popup_area = Self::popup_area(area);
+        f.render_widget(Clear, popup_area);
+
+        let paragraph = Paragraph::new(Self::lines(stage, theme))
+            .block(Block::default().borders(Borders::ALL).title(format!(
+                "Stage {} - ↑↓/PgUp/PgDn/g/G to scroll, Enter/Esc to close",
+                stage.stage_id
+            )))
+            .style(Style::default().fg(theme.text_fg).bg(theme.background))
+            .wrap(Wrap { trim: false })
+            .scroll((scroll.min(Self::max_scroll(stage, area, theme)), 0));
+
+        f.render_widget(paragraph, popup_area);
+    }
+
+    fn lines(stage: &Stage, theme: &Theme) -> Vec<Line<'static>> {
+        let duration = match (stage.submission_time, stage.completion_time) {
+            (Some(start), Some(end)) => format_duration((end - start).num_milliseconds().max(0) as u64),
+            (Some(_), None) => "Running".to_string(),
+            _ => "Pending".to_string(),
+        };
+
+        let parent_ids: Vec<_> = stage.parent_ids.iter().map(|id| id.to_string()).collect();
+
+        let peak_heap = stage.executor_peak_metrics.values()
+            .max_by_key(|m| m.peak_jvm_used_heap)
+            .filter(|m| m.peak_jvm_used_heap > 0)
+            .map(|m| format!("{} (executor {})", format_bytes(m.peak_jvm_used_heap), m.executor_id))
+            .unwrap_or_else(|| "N/A".to_string());
+
+        let mut lines = vec![
+            detail_line("Stage", format!("{}.{}", stage.stage_id, stage.stage_attempt_id), theme),
+            detail_line("Name", stage.name.clone(), theme),
+            detail_line("Status", stage.status.to_string(), theme),
+            detail_line("Tasks", stage.num_tasks.to_string(), theme),
+            detail_line("Duration", duration, theme),
+            detail_line("Parent Stages", if parent_ids.is_empty() { "None".to_string() } else { parent_ids.join(", ") }, theme),
+            detail_line("Peak Heap", peak_heap, theme),
+        ];
+        if let Some(size) = stage.task_binary_size {
+            let mut line = detail_line("Task Binary", format_bytes(size), theme);
+            // Spark itself warns about task binaries over 1000 KiB
+            if size > TASK_BINARY_WARN_BYTES {
+                line.push_span(Span::styled(" ⚠ large closure", Style::default().fg(theme.status_warning)));
+            }
+            lines.push(line);
+        }
+        if let Some(reason) = &stage.failure_reason {
+            // The reason usually ends in a stack trace; its first line says what happened
+            lines.push(Line::from(vec![
+                Span::styled("Failure Reason: ", Style::default().fg(theme.status_failed)),
+                Span::styled(reason.lines().next().unwrap_or_default().to_string(), Style::default().fg(theme.status_failed)),
+            ]));
+        }
+        lines.extend([
+            Line::from(""),
+            section_line("SQL", theme),
+            detail_line(
+                "SQL Execution ID",
+                stage.sql_execution_id.map(|id| id.to_string()).unwrap_or_else(|| "N/A".to_string()),
+                theme,
+            ),
+            Line::from(""),
+            section_line("RDDs", theme),
+        ]);
+
+        if stage.rdd_info.is_empty() {
+            lines.push(Line::from(Span::styled("No RDD info recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            for rdd in &stage.rdd_info {
+                let cached = if rdd.num_cached_partitions > 0 {
+                    format!(", {} cached ({} mem / {} disk)", rdd.num_cached_partitions, format_bytes(rdd.memory_size), format_bytes(rdd.disk_size))
+                } else {
+                    String::new()
+                };
+                lines.push(detail_line(
+                    &format!("#{}", rdd.rdd_id),
+                    format!("{} ({} partitions{})", rdd.name, rdd.num_partitions, cached),
+                    theme,
+                ));
+            }
+        }
+
+        lines.push(Line::from(""));
+        lines.push(section_line("Call Site", theme));
+        if stage.details.is_empty() {
+            lines.push(Line::from(Span::styled("No call site recorded", Style::default().fg(theme.muted_fg))));
+        } else {
+            lines.extend(stage.details.lines().map(|line| Line::from(line.to_string())));
+        }
+
+        lines
+    }
+}
@@ -1,21 +1,40 @@
+use chrono_tz::Tz;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::Span,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
-use crate::models::{StageStatus, SparkEventLog};
+use super::{filtered_title, format_bytes, format_duration, format_time, row_style};
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, Stage, StageStatus, SparkEventLog};
+use std::collections::{HashMap, HashSet};
 
 pub struct StagesTab;
 
 impl StagesTab {
+    /// Stages in table order, so a selected row index maps back to its stage.
+    pub fn sorted_stages(event_log: &SparkEventLog, failed_only: bool) -> Vec<&Stage> {
+        let mut stages: Vec<_> = event_log.stages.values()
+            .filter(|s| !failed_only || matches!(s.status, StageStatus::Failed))
+            .collect();
+        stages.sort_by_key(|stage| (stage.stage_id, stage.stage_attempt_id));
+        stages
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
         table_state: &TableState,
+        zebra_stripe: bool,
+        failed_only: bool,
+        timezone: Tz,
+        theme: &Theme,
     ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -23,34 +42,63 @@ impl StagesTab {
             .split(area);
 
         // Summary section
+        let mut attempts_per_stage: HashMap<u64, usize> = HashMap::new();
+        for stage in event_log.stages.values() {
+            *attempts_per_stage.entry(stage.stage_id).or_insert(0) += 1;
+        }
+        let stage_retries = attempts_per_stage.values().filter(|attempts| **attempts > 1).count();
+
+        let stages_with_spill = aggregates.stage_task_counts.values()
+            .filter(|s| s.memory_spilled_bytes > 0 || s.disk_spilled_bytes > 0)
+            .count();
+
         let summary_text = format!(
-            "Total Stages: {} | Active: {} | Complete: {} | Failed: {} | Pending: {}",
+            "Total Stages: {} | Active: {} | Complete: {} | Failed: {} | Pending: {} | Stage Retries: {} | Stages with Spill: {}",
             event_log.stages.len(),
             event_log.stages.values().filter(|s| matches!(s.status, StageStatus::Active)).count(),
             event_log.stages.values().filter(|s| matches!(s.status, StageStatus::Complete)).count(),
             event_log.stages.values().filter(|s| matches!(s.status, StageStatus::Failed)).count(),
             event_log.stages.values().filter(|s| matches!(s.status, StageStatus::Pending)).count(),
+            stage_retries,
+            stages_with_spill,
         );
 
-        let summary = ratatui::widgets::Paragraph::new(summary_text)
+        let unschedulable: HashSet<(u64, u64)> = event_log.unschedulable_events.iter()
+            .map(|e| (e.stage_id, e.stage_attempt_id))
+            .collect();
+
+        let mut summary_lines = vec![Line::from(summary_text)];
+        if !unschedulable.is_empty() {
+            let mut stage_ids: Vec<_> = unschedulable.iter().map(|(stage_id, _)| *stage_id).collect();
+            stage_ids.sort();
+            stage_ids.dedup();
+            summary_lines.push(Line::from(Span::styled(
+                format!(
+                    "⚠ UNSCHEDULABLE TASKSET: stage {}",
+                    stage_ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(", "),
+                ),
+                Style::default().fg(theme.accent).add_modifier(Modifier::BOLD),
+            )));
+        }
+
+        let summary = ratatui::widgets::Paragraph::new(summary_lines)
             .block(Block::default().borders(Borders::ALL).title("Stages Summary"))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text_fg));
 
         f.render_widget(summary, chunks[0]);
 
         // Stages table
-        let header_cells = ["Stage ID", "Name", "Status", "Tasks", "Submission Time", "Duration", "RDDs"]
+        let header_cells = ["Stage ID", "Name", "Status", "Tasks", "Submission Time", "Duration", "Spill", "Failed %", "RDDs"]
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-        let mut stages: Vec<_> = event_log.stages.values().collect();
-        stages.sort_by_key(|stage| stage.stage_id);
+        let stages = Self::sorted_stages(event_log, failed_only);
 
-        let rows = stages.iter().map(|stage| {
+        let rows = stages.iter().enumerate().map(|(i, stage)| {
             let duration = if let (Some(submission), Some(completion)) = (stage.submission_time, stage.completion_time) {
-                format!("{}ms", (completion - submission).num_milliseconds())
+                format_duration((completion - submission).num_milliseconds().max(0) as u64)
             } else if stage.submission_time.is_some() {
                 "Running".to_string()
             } else {
@@ -58,32 +106,66 @@ impl StagesTab {
             };
 
             let status_style = match stage.status {
-                StageStatus::Active => Style::default().fg(Color::Blue),
-                StageStatus::Complete => Style::default().fg(Color::Green),
-                StageStatus::Failed => Style::default().fg(Color::Red),
-                StageStatus::Pending => Style::default().fg(Color::Gray),
-            };
-
-            let status_text = match stage.status {
-                StageStatus::Active => "ACTIVE",
-                StageStatus::Complete => "COMPLETE",
-                StageStatus::Failed => "FAILED",
-                StageStatus::Pending => "PENDING",
+                StageStatus::Active => Style::default().fg(theme.status_running),
+                StageStatus::Complete => Style::default().fg(theme.status_success),
+                StageStatus::Failed => Style::default().fg(theme.status_failed),
+                StageStatus::Pending => Style::default().fg(theme.muted_fg),
             };
 
             let submission_time = stage.submission_time
-                .map(|t| t.format("%Y-%m-%d %H:%M:%S").to_string())
+                .map(|t| format_time(t, "%Y-%m-%d %H:%M:%S", timezone))
                 .unwrap_or_else(|| "N/A".to_string());
 
+            let stage_summary = aggregates.stage_task_counts
+                .get(&(stage.stage_id, stage.stage_attempt_id))
+                .cloned()
+                .unwrap_or_default();
+            let (memory_spill, disk_spill) = (stage_summary.memory_spilled_bytes, stage_summary.disk_spilled_bytes);
+            let spill = if memory_spill > 0 || disk_spill > 0 {
+                Span::styled(
+                    format!("M/D: {}/{}", format_bytes(memory_spill), format_bytes(disk_spill)),
+                    Style::default().fg(theme.status_failed),
+                )
+            } else {
+                Span::raw("-")
+            };
+
+            // Retried stages share an ID, so tell their attempts apart by name
+            let name = if attempts_per_stage.get(&stage.stage_id).copied().unwrap_or(0) > 1 {
+                format!("{} (attempt {})", stage.name, stage.stage_attempt_id + 1)
+            } else {
+                stage.name.clone()
+            };
+
+            let failure_rate = if stage.num_tasks > 0 {
+                stage_summary.failed_tasks as f64 / stage.num_tasks as f64 * 100.0
+            } else {
+                0.0
+            };
+            let failure_style = if failure_rate > 10.0 {
+                Style::default().fg(theme.status_failed)
+            } else if failure_rate > 0.0 {
+                Style::default().fg(theme.status_warning)
+            } else {
+                Style::default()
+            };
+
             Row::new(vec![
                 Cell::from(stage.stage_id.to_string()),
-                Cell::from(stage.name.clone()),
-                Cell::from(Span::styled(status_text, status_style)),
+                Cell::from(name),
+                Cell::from(Span::styled(stage.status.to_string(), status_style)),
                 Cell::from(stage.num_tasks.to_string()),
                 Cell::from(submission_time),
                 Cell::from(duration),
+                Cell::from(spill),
+                Cell::from(Span::styled(format!("{:.1}%", failure_rate), failure_style)),
                 Cell::from(stage.rdd_info.len().to_string()),
             ])
+            .style(if unschedulable.contains(&(stage.stage_id, stage.stage_attempt_id)) {
+                row_style(i, zebra_stripe, theme).fg(theme.accent)
+            } else {
+                row_style(i, zebra_stripe, theme)
+            })
         });
 
         let table = Table::new(
@@ -95,15 +177,18 @@ impl StagesTab {
                 Constraint::Length(8),  // Tasks
                 Constraint::Length(19), // Submission Time
                 Constraint::Length(12), // Duration
+                Constraint::Length(22), // Spill
+                Constraint::Length(9),  // Failed %
                 Constraint::Length(6),  // RDDs
             ]
         )
             .header(header)
-            .block(Block::default().borders(Borders::ALL).title("Stages"))
+            .block(Block::default().borders(Borders::ALL).title(filtered_title("Stages", failed_only)))
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
@@ -0,0 +1,100 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use super::{format_bytes, format_duration};
+use crate::analysis::compute_spill_summary;
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, SparkEventLog};
+
+pub struct SummaryTab;
+
+impl SummaryTab {
+    pub fn draw(
+        f: &mut Frame,
+        area: ratatui::layout::Rect,
+        event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
+        theme: &Theme,
+    ) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(7), Constraint::Min(0)])
+            .split(area);
+
+        // Application overview
+        let app_info = &event_log.application_info;
+        let summary = &aggregates.app_totals;
+        let duration = summary.duration_ms
+            .map(format_duration)
+            .unwrap_or_else(|| "Running".to_string());
+        let overview_text = format!(
+            "Application: {} ({}) | Duration: {}\nUser: {} | Spark Version: {}\n\
+            Jobs: {} ({} failed) | Stages: {} ({} failed) | Tasks: {} ({} failed) | Executors: {} | SQL Executions: {}\n\
+            Executor Time: {} | GC Time: {} ({:.1}%)\n\
+            Input: {} | Output: {} | Shuffle Read: {} | Shuffle Write: {}",
+            summary.app_name,
+            summary.app_id,
+            duration,
+            app_info.user,
+            app_info.spark_version,
+            summary.total_jobs,
+            summary.failed_jobs,
+            summary.total_stages,
+            summary.failed_stages,
+            summary.total_tasks,
+            summary.failed_tasks,
+            event_log.executors.len(),
+            event_log.sql_executions.len(),
+            format_duration(summary.total_executor_time_ms),
+            format_duration(summary.total_gc_time_ms),
+            summary.gc_overhead_pct,
+            format_bytes(summary.total_input_bytes),
+            format_bytes(summary.total_output_bytes),
+            format_bytes(summary.total_shuffle_read_bytes),
+            format_bytes(summary.total_shuffle_write_bytes),
+        );
+
+        let overview = Paragraph::new(overview_text)
+            .block(Block::default().borders(Borders::ALL).title("Application Overview"))
+            .style(Style::default().fg(theme.text_fg));
+
+        f.render_widget(overview, chunks[0]);
+
+        // Spill section
+        let spill = compute_spill_summary(event_log);
+        let spill_style = |bytes: u64| {
+            if bytes > 0 {
+                Style::default().fg(theme.status_failed)
+            } else {
+                Style::default().fg(theme.text_fg)
+            }
+        };
+
+        let spill_lines = vec![
+            Line::from(vec![
+                Span::raw("Memory Spilled: "),
+                Span::styled(format_bytes(spill.memory_bytes_spilled), spill_style(spill.memory_bytes_spilled)),
+            ]),
+            Line::from(vec![
+                Span::raw("Disk Spilled: "),
+                Span::styled(format_bytes(spill.disk_bytes_spilled), spill_style(spill.disk_bytes_spilled)),
+            ]),
+            Line::from(format!(
+                "Tasks with Spill: {} / {}",
+                spill.tasks_with_spill,
+                event_log.tasks.len()
+            )),
+        ];
+
+        let spill_summary = Paragraph::new(spill_lines)
+            .block(Block::default().borders(Borders::ALL).title("Spill"))
+            .style(Style::default().fg(theme.text_fg));
+
+        f.render_widget(spill_summary, chunks[1]);
+    }
+}
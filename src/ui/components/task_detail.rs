@@ -0,0 +1,107 @@
+use chrono_tz::Tz;
+use ratatui::{
+    style::{Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{centered_rect, detail_line, format_bytes, format_bytes_per_sec, format_duration, format_time, section_line};
+use crate::config::Theme;
+use crate::models::Task;
+
+pub struct TaskDetailPopup;
+
+impl TaskDetailPopup {
+    pub fn draw(f: &mut Frame, area: ratatui::layout::Rect, task: &Task, timezone: Tz, theme: &Theme) {
+        let popup_area = centered_rect(60, 70, area);
+        f.render_widget(Clear, popup_area);
+
+        let duration = if let Some(finish_time) = task.finish_time {
+            format_duration((finish_time - task.launch_time).num_milliseconds().max(0) as u64)
+        } else {
+            "Running".to_string()
+        };
+
+        let mut lines = vec![
+            detail_line("Task ID", task.task_id.to_string(), theme),
+            detail_line("Stage", format!("{}.{}", task.stage_id, task.stage_attempt_id), theme),
+            detail_line("Index", task.task_index.to_string(), theme),
+            detail_line("Partition", task.partition_id.to_string(), theme),
+            detail_line("Status", task.status.to_string(), theme),
+            detail_line("Executor", format!("{} ({})", task.executor_id, task.host), theme),
+            detail_line("Launch Time", format_time(task.launch_time, "%Y-%m-%d %H:%M:%S", timezone), theme),
+            detail_line("Duration", duration, theme),
+        ];
+        if let Some(reason) = &task.failure_reason {
+            lines.push(Line::from(vec![
+                Span::styled("Failure Reason: ", Style::default().fg(theme.status_failed)),
+                Span::raw(reason.clone()),
+            ]));
+        }
+        lines.push(Line::from(""));
+
+        if let Some(metrics) = &task.metrics {
+            lines.push(section_line("Timing", theme));
+            lines.push(detail_line("Scheduler Delay", format_duration(metrics.scheduler_delay), theme));
+            lines.push(detail_line("Deserialization Time", format_duration(metrics.deserialization_time), theme));
+            lines.push(detail_line("Executor Run Time", format_duration(metrics.execution_time), theme));
+            // CPU time is reported in nanoseconds, run time in milliseconds
+            let cpu_time_ms = metrics.cpu_time / 1_000_000;
+            lines.push(detail_line("CPU Time", format_duration(cpu_time_ms), theme));
+            if metrics.execution_time > 0 {
+                let efficiency = cpu_time_ms as f64 / metrics.execution_time as f64 * 100.0;
+                let hint = if efficiency < 50.0 { " (I/O-bound)" } else { "" };
+                lines.push(detail_line("CPU Efficiency", format!("{:.1}%{}", efficiency, hint), theme));
+            }
+            lines.push(detail_line("Result Serialization", format_duration(metrics.result_serialization_time), theme));
+            // Result fetches are usually sub-second, so keep millisecond precision
+            let result_fetch = if metrics.result_getting_time < 1000 {
+                format!("{}ms", metrics.result_getting_time)
+            } else {
+                format_duration(metrics.result_getting_time)
+            };
+            lines.push(detail_line("Result Fetch", result_fetch, theme));
+            lines.push(detail_line("GC Time", format_duration(metrics.gc_time), theme));
+            lines.push(Line::from(""));
+
+            lines.push(section_line("Data", theme));
+            if let Some(input) = &metrics.input_metrics {
+                lines.push(detail_line("Input", format!("{} / {} records", format_bytes(input.bytes_read), input.records_read), theme));
+                lines.push(detail_line("Input rate", format_bytes_per_sec(input.bytes_read, metrics.execution_time), theme));
+            }
+            if let Some(output) = &metrics.output_metrics {
+                lines.push(detail_line("Output", format!("{} / {} records", format_bytes(output.bytes_written), output.records_written), theme));
+            }
+            if let Some(shuffle_read) = &metrics.shuffle_read_metrics {
+                lines.push(detail_line(
+                    "Shuffle Read",
+                    format_bytes(shuffle_read.local_bytes_read + shuffle_read.remote_bytes_read),
+                    theme,
+                ));
+                if let Some(ratio) = shuffle_read.push_merge_ratio() {
+                    lines.push(detail_line("Push Shuffle Merge", format!("{:.1}%", ratio), theme));
+                }
+            }
+            if let Some(shuffle_write) = &metrics.shuffle_write_metrics {
+                lines.push(detail_line("Shuffle Write", format_bytes(shuffle_write.bytes_written), theme));
+                lines.push(detail_line("Shuffle write rate", format_bytes_per_sec(shuffle_write.bytes_written, metrics.execution_time), theme));
+            }
+            lines.push(detail_line(
+                "Spilled (Memory/Disk)",
+                format!("{} / {}", format_bytes(metrics.memory_bytes_spilled), format_bytes(metrics.disk_bytes_spilled)),
+                theme,
+            ));
+            lines.push(detail_line("Peak Execution Memory", format_bytes(metrics.peak_execution_memory), theme));
+        } else {
+            lines.push(Line::from(Span::styled("No task metrics recorded", Style::default().fg(theme.muted_fg))));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!("Task {} - Enter/Esc to close", task.task_id)))
+            .style(Style::default().fg(theme.text_fg).bg(theme.background))
+            .wrap(Wrap { trim: false });
+
+        f.render_widget(paragraph, popup_area);
+    }
+}
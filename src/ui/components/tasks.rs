@@ -1,21 +1,38 @@
+use chrono_tz::Tz;
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
-    text::Span,
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Cell, Row, Table, TableState},
     Frame,
 };
 
-use crate::models::{TaskStatus, SparkEventLog};
+use super::{filtered_title, format_bytes, format_duration, format_time, row_style};
+use crate::config::Theme;
+use crate::models::{EventLogAggregates, Task, TaskStatus, SparkEventLog};
 
 pub struct TasksTab;
 
 impl TasksTab {
+    /// Tasks in table order, so a selected row index maps back to its task.
+    pub fn sorted_tasks<'a>(event_log: &'a SparkEventLog, aggregates: &EventLogAggregates, failed_only: bool) -> Vec<&'a Task> {
+        aggregates.task_order.iter()
+            .filter_map(|task_id| event_log.tasks.get(task_id))
+            .filter(|t| !failed_only || matches!(t.status, TaskStatus::Failed))
+            .collect()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn draw(
         f: &mut Frame,
         area: ratatui::layout::Rect,
         event_log: &SparkEventLog,
+        aggregates: &EventLogAggregates,
         table_state: &TableState,
+        zebra_stripe: bool,
+        failed_only: bool,
+        timezone: Tz,
+        theme: &Theme,
     ) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -23,38 +40,57 @@ impl TasksTab {
             .split(area);
 
         // Summary section
-        let total_tasks = event_log.tasks.len();
-        let successful_tasks = event_log.tasks.values().filter(|t| matches!(t.status, TaskStatus::Success)).count();
-        let failed_tasks = event_log.tasks.values().filter(|t| matches!(t.status, TaskStatus::Failed)).count();
-        let killed_tasks = event_log.tasks.values().filter(|t| matches!(t.status, TaskStatus::Killed)).count();
-        let running_tasks = event_log.tasks.values().filter(|t| matches!(t.status, TaskStatus::Running)).count();
-
-        // Calculate total execution time and data processed
-        let total_execution_time: u64 = event_log.tasks.values()
-            .filter_map(|t| t.metrics.as_ref())
-            .map(|m| m.execution_time)
-            .sum();
-
-        let total_input_bytes: u64 = event_log.tasks.values()
-            .filter_map(|t| t.metrics.as_ref())
-            .filter_map(|m| m.input_metrics.as_ref())
-            .map(|i| i.bytes_read)
-            .sum();
-
-        let summary_text = format!(
-            "Total Tasks: {} | Success: {} | Failed: {} | Killed: {} | Running: {}\nTotal Execution Time: {:.1}s | Total Input Data: {}",
-            total_tasks,
-            successful_tasks,
-            failed_tasks, 
-            killed_tasks,
-            running_tasks,
-            total_execution_time as f64 / 1000.0,
-            format_bytes(total_input_bytes)
-        );
-
-        let summary = ratatui::widgets::Paragraph::new(summary_text)
+        let totals = &aggregates.app_totals;
+        let counts = &aggregates.task_status_counts;
+
+        let total_execution_time = totals.total_executor_time_ms;
+        let total_input_bytes = totals.total_input_bytes;
+
+        let tasks = Self::sorted_tasks(event_log, aggregates, failed_only);
+
+        // Share of shuffle blocks fetched locally, over the tasks in view
+        let (local_blocks, remote_blocks) = if failed_only {
+            aggregates.failed_shuffle_blocks
+        } else {
+            aggregates.shuffle_blocks
+        };
+
+        let locality = if local_blocks + remote_blocks > 0 {
+            let ratio = local_blocks as f64 / (local_blocks + remote_blocks) as f64 * 100.0;
+            let color = if ratio > 80.0 {
+                theme.status_success
+            } else if ratio >= 50.0 {
+                theme.status_warning
+            } else {
+                theme.status_failed
+            };
+            Span::styled(format!("{:.1}% local", ratio), Style::default().fg(color))
+        } else {
+            Span::styled("N/A", Style::default().fg(theme.muted_fg))
+        };
+
+        let summary_lines = vec![
+            Line::from(format!(
+                "Total Tasks: {} | Success: {} | Failed: {} | Killed: {} | Running: {}",
+                totals.total_tasks,
+                counts.succeeded,
+                counts.failed,
+                counts.killed,
+                counts.running,
+            )),
+            Line::from(vec![
+                Span::raw(format!(
+                    "Total Execution Time: {:.1}s | Total Input Data: {} | Shuffle Locality: ",
+                    total_execution_time as f64 / 1000.0,
+                    format_bytes(total_input_bytes)
+                )),
+                locality,
+            ]),
+        ];
+
+        let summary = ratatui::widgets::Paragraph::new(summary_lines)
             .block(Block::default().borders(Borders::ALL).title("Tasks Summary"))
-            .style(Style::default().fg(Color::White));
+            .style(Style::default().fg(theme.text_fg));
 
         f.render_widget(summary, chunks[0]);
 
@@ -64,39 +100,30 @@ impl TasksTab {
             "Launch Time", "Duration", "CPU Time", "GC Time", "Input", "Output", "Spilled"
         ]
             .iter()
-            .map(|h| Cell::from(*h).style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)));
+            .map(|h| Cell::from(*h).style(Style::default().fg(theme.header_fg).add_modifier(Modifier::BOLD)));
 
         let header = Row::new(header_cells).height(1).bottom_margin(1);
 
-        let mut tasks: Vec<_> = event_log.tasks.values().collect();
-        tasks.sort_by_key(|task| task.task_id);
-
-        let rows = tasks.iter().map(|task| {
+        let rows = tasks.iter().enumerate().map(|(i, task)| {
             let duration = if let Some(finish_time) = task.finish_time {
-                format!("{}ms", (finish_time - task.launch_time).num_milliseconds())
+                format_duration((finish_time - task.launch_time).num_milliseconds().max(0) as u64)
             } else {
                 "Running".to_string()
             };
 
             let status_style = match task.status {
-                TaskStatus::Running => Style::default().fg(Color::Blue),
-                TaskStatus::Success => Style::default().fg(Color::Green),
-                TaskStatus::Failed => Style::default().fg(Color::Red),
-                TaskStatus::Killed => Style::default().fg(Color::Magenta),
-            };
-
-            let status_text = match task.status {
-                TaskStatus::Running => "RUNNING",
-                TaskStatus::Success => "SUCCESS",
-                TaskStatus::Failed => "FAILED",
-                TaskStatus::Killed => "KILLED",
+                TaskStatus::Running => Style::default().fg(theme.status_running),
+                TaskStatus::Success => Style::default().fg(theme.status_success),
+                TaskStatus::Failed => Style::default().fg(theme.status_failed),
+                TaskStatus::Killed => Style::default().fg(theme.status_killed),
             };
 
             // Extract metrics
             let (cpu_time, gc_time, input_data, output_data, spilled_data) = if let Some(metrics) = &task.metrics {
                 (
-                    format!("{}ms", metrics.cpu_time),
-                    format!("{}ms", metrics.gc_time),
+                    // Spark reports executor CPU time in nanoseconds
+                    format_duration(metrics.cpu_time / 1_000_000),
+                    format_duration(metrics.gc_time),
                     metrics.input_metrics.as_ref()
                         .map(|i| format_bytes(i.bytes_read))
                         .unwrap_or_else(|| "0 B".to_string()),
@@ -113,10 +140,10 @@ impl TasksTab {
                 Cell::from(task.task_id.to_string()),
                 Cell::from(format!("{}.{}", task.stage_id, task.stage_attempt_id)),
                 Cell::from(task.partition_id.to_string()),
-                Cell::from(Span::styled(status_text, status_style)),
+                Cell::from(Span::styled(task.status.to_string(), status_style)),
                 Cell::from(task.executor_id.clone()),
                 Cell::from(task.host.clone()),
-                Cell::from(task.launch_time.format("%H:%M:%S").to_string()),
+                Cell::from(format_time(task.launch_time, "%H:%M:%S", timezone)),
                 Cell::from(duration),
                 Cell::from(cpu_time),
                 Cell::from(gc_time),
@@ -124,6 +151,7 @@ impl TasksTab {
                 Cell::from(output_data),
                 Cell::from(spilled_data),
             ])
+            .style(row_style(i, zebra_stripe, theme))
         });
 
         let table = Table::new(
@@ -145,37 +173,16 @@ impl TasksTab {
             ]
         )
             .header(header)
-            .block(Block::default().borders(Borders::ALL).title("Tasks"))
+            .block(Block::default().borders(Borders::ALL).title(filtered_title("Tasks", failed_only)))
             .column_spacing(1)
             .highlight_style(
                 Style::default()
-                    .bg(Color::DarkGray)
+                    .bg(theme.row_highlight_bg)
+                    .fg(theme.row_highlight_fg)
                     .add_modifier(Modifier::BOLD),
             )
             .highlight_symbol(">> ");
 
         f.render_stateful_widget(table, chunks[1], &mut table_state.clone());
     }
-}
-
-fn format_bytes(bytes: u64) -> String {
-    const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
-    
-    if bytes == 0 {
-        return "0 B".to_string();
-    }
-    
-    let mut size = bytes as f64;
-    let mut unit_index = 0;
-    
-    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
-        size /= 1024.0;
-        unit_index += 1;
-    }
-    
-    if unit_index == 0 {
-        format!("{} {}", bytes, UNITS[unit_index])
-    } else {
-        format!("{:.1} {}", size, UNITS[unit_index])
-    }
 }
\ No newline at end of file
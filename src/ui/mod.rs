@@ -6,13 +6,16 @@ pub use state::*;
 
 use ratatui::{
     layout::{Constraint, Direction, Layout},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Tabs},
     Frame,
 };
 
-use crate::models::SparkEventLog;
+use crate::analysis::{detect_cluster_manager, detect_deployment_mode, extract_yarn_info, ClusterManager, DeploymentMode};
+use crate::models::{EventLogAggregates, ParseMetadata, SparkEventLog};
 
+#[derive(Default)]
 pub struct UI;
 
 impl UI {
@@ -20,8 +23,11 @@ impl UI {
         Self
     }
 
-    pub fn draw(&self, f: &mut Frame, event_log: &SparkEventLog, state: &AppState) {
+    pub fn draw(&self, f: &mut Frame, event_log: &SparkEventLog, metadata: &ParseMetadata, aggregates: &EventLogAggregates, state: &AppState) {
         let size = f.area();
+        let theme = &state.theme;
+
+        f.render_widget(Block::default().style(Style::default().fg(theme.text_fg).bg(theme.background)), size);
 
         // Create the main layout
         let chunks = Layout::default()
@@ -35,7 +41,7 @@ impl UI {
             .split(size);
 
         // Draw header
-        self.draw_header(f, chunks[0], event_log);
+        self.draw_header(f, chunks[0], event_log, metadata, state);
 
         // Draw tab bar
         self.draw_tab_bar(f, chunks[1], state);
@@ -43,73 +49,178 @@ impl UI {
         // Draw content based on selected tab
         match state.selected_tab {
             TabIndex::Jobs => {
-                JobsTab::draw(f, chunks[2], event_log, &state.jobs_table_state);
+                JobsTab::draw(f, chunks[2], event_log, aggregates, &state.jobs_table_state, state.zebra_stripe, state.show_failed_only, state.display_timezone, theme);
             }
             TabIndex::Stages => {
-                StagesTab::draw(f, chunks[2], event_log, &state.stages_table_state);
+                StagesTab::draw(f, chunks[2], event_log, aggregates, &state.stages_table_state, state.zebra_stripe, state.show_failed_only, state.display_timezone, theme);
             }
             TabIndex::Tasks => {
-                TasksTab::draw(f, chunks[2], event_log, &state.tasks_table_state);
+                TasksTab::draw(f, chunks[2], event_log, aggregates, &state.tasks_table_state, state.zebra_stripe, state.show_failed_only, state.display_timezone, theme);
             }
             TabIndex::Executors => {
-                ExecutorsTab::draw(f, chunks[2], event_log, &state.executors_table_state);
+                ExecutorsTab::draw(f, chunks[2], event_log, aggregates, &state.executors_table_state, state.executor_timeline, state.zebra_stripe, state.show_failed_only, theme);
             }
             TabIndex::Sql => {
-                SqlTab::draw(f, chunks[2], event_log, &state.sql_table_state);
+                SqlTab::draw(f, chunks[2], event_log, aggregates, &state.sql_table_state, state.zebra_stripe, state.display_timezone, theme);
             }
             TabIndex::Environment => {
-                EnvironmentTab::draw(f, chunks[2], event_log);
+                EnvironmentTab::draw(f, chunks[2], event_log, state.zebra_stripe, state.show_misc_processes, state.display_timezone, theme);
+            }
+            TabIndex::Summary => {
+                SummaryTab::draw(f, chunks[2], event_log, aggregates, theme);
             }
         }
 
         // Draw footer
-        self.draw_footer(f, chunks[3]);
+        self.draw_footer(f, chunks[3], state);
+
+        if state.detail_open {
+            self.draw_detail_popup(f, size, event_log, aggregates, state);
+        }
+
+        if state.warnings_open {
+            ParseWarningsPopup::draw(f, size, &event_log.parse_warnings, &state.warnings_table_state, state.zebra_stripe, theme);
+        }
+
+        if let Some(message) = &state.error_message {
+            ErrorPopup::draw(f, size, message, theme);
+        }
+    }
+
+    fn draw_detail_popup(&self, f: &mut Frame, area: ratatui::layout::Rect, event_log: &SparkEventLog, aggregates: &EventLogAggregates, state: &AppState) {
+        match state.selected_tab {
+            TabIndex::Jobs => {
+                let jobs = JobsTab::sorted_jobs(event_log, state.show_failed_only);
+                if let Some(job) = state.jobs_table_state.selected().and_then(|i| jobs.get(i)) {
+                    let sql_execution_id = event_log.sql_execution_for_job(job.job_id).map(|e| e.execution_id);
+                    JobDetailPopup::draw(f, area, job, sql_execution_id, state.display_timezone, &state.theme);
+                }
+            }
+            TabIndex::Stages => {
+                let stages = StagesTab::sorted_stages(event_log, state.show_failed_only);
+                if let Some(stage) = state.stages_table_state.selected().and_then(|i| stages.get(i)) {
+                    StageDetailPopup::draw(f, area, stage, state.detail_scroll, &state.theme);
+                }
+            }
+            TabIndex::Tasks => {
+                let tasks = TasksTab::sorted_tasks(event_log, aggregates, state.show_failed_only);
+                if let Some(task) = state.tasks_table_state.selected().and_then(|i| tasks.get(i)) {
+                    TaskDetailPopup::draw(f, area, task, state.display_timezone, &state.theme);
+                }
+            }
+            TabIndex::Executors => {
+                let executors = ExecutorsTab::sorted_executors(event_log, aggregates, state.show_failed_only);
+                if let Some(executor) = state.executors_table_state.selected().and_then(|i| executors.get(i)) {
+                    ExecutorDetailPopup::draw(f, area, executor, &event_log.block_manager_migrations, &state.theme);
+                }
+            }
+            TabIndex::Sql => {
+                let executions = SqlTab::sorted_executions(event_log);
+                if let Some(execution) = state.sql_table_state.selected().and_then(|i| executions.get(i)) {
+                    SqlDetailPopup::draw(f, area, execution, state.detail_scroll, state.show_initial_plan, state.display_timezone, &state.theme);
+                }
+            }
+            _ => {}
+        }
     }
 
-    fn draw_header(&self, f: &mut Frame, area: ratatui::layout::Rect, event_log: &SparkEventLog) {
-        let app_info = &event_log.application_info;
-        let duration = if let Some(end_time) = app_info.end_time {
-            format!(" ({}ms)", (end_time - app_info.start_time).num_milliseconds())
+    fn draw_header(&self, f: &mut Frame, area: ratatui::layout::Rect, event_log: &SparkEventLog, metadata: &ParseMetadata, state: &AppState) {
+        let attempts = &event_log.application_attempts;
+        let app_info = attempts.get(state.selected_attempt).unwrap_or(&event_log.application_info);
+        let theme = &state.theme;
+        let duration = if let Some(duration) = event_log.total_wall_clock_time() {
+            format!(" ({})", format_duration(duration.num_milliseconds().max(0) as u64))
         } else {
             " (Running)".to_string()
         };
 
+        let attempt_id = app_info.app_attempt_id.as_ref()
+            .map(|id| format!(" (Attempt {})", id))
+            .unwrap_or_default();
+
         let header_text = format!(
-            "Application: {} | ID: {} | User: {} | Spark Version: {}{}",
+            "Application: {} | ID: {}{} | User: {} | Spark Version: {}{}",
             app_info.app_name,
             app_info.app_id,
+            attempt_id,
             app_info.user,
             app_info.spark_version,
             duration
         );
 
-        let paragraph = Paragraph::new(header_text)
-            .style(Style::default().fg(Color::Cyan))
+        let mut header_spans = vec![Span::raw(header_text)];
+        match app_info.exit_code {
+            Some(0) => header_spans.push(Span::styled(" | Exit: OK", Style::default().fg(theme.status_success))),
+            Some(code) => header_spans.push(Span::styled(format!(" | Exit: {} ⚠", code), Style::default().fg(theme.status_failed))),
+            None => {}
+        }
+
+        let mut header_text = String::new();
+
+        let deploy_mode = detect_deployment_mode(&event_log.environment);
+        let cluster_manager = detect_cluster_manager(&event_log.environment);
+        if deploy_mode != DeploymentMode::Unknown || cluster_manager != ClusterManager::Unknown {
+            header_text.push_str(&format!(" | Deploy: {} on {}", deploy_mode, cluster_manager));
+        }
+
+        if let Some(yarn_info) = extract_yarn_info(&event_log.environment) {
+            if let Some(queue) = &yarn_info.queue {
+                header_text.push_str(&format!(" | Queue: {}", queue));
+            }
+            if !yarn_info.tags.is_empty() {
+                header_text.push_str(&format!(" | Tags: {}", yarn_info.tags.join(", ")));
+            }
+        }
+
+        if attempts.len() > 1 {
+            header_text.push_str(&format!(" | Attempt: {}/{}", state.selected_attempt + 1, attempts.len()));
+        }
+
+        header_text.push_str(&format!(" | Log: {}", format_bytes(metadata.file_size_bytes)));
+
+        if !event_log.parse_warnings.is_empty() {
+            header_text.push_str(&format!(" | ⚠ {} parse warnings", event_log.parse_warnings.len()));
+        }
+
+        header_spans.push(Span::raw(header_text));
+
+        let paragraph = Paragraph::new(Line::from(header_spans))
+            .style(Style::default().fg(theme.header_fg))
             .block(Block::default().borders(Borders::ALL).title("Spark Application"));
 
         f.render_widget(paragraph, area);
     }
 
     fn draw_tab_bar(&self, f: &mut Frame, area: ratatui::layout::Rect, state: &AppState) {
-        let tab_titles = vec!["Jobs (1)", "Stages (2)", "Tasks (3)", "Executors (4)", "SQL (5)", "Environment (6)"];
+        let theme = &state.theme;
+        let tab_titles = vec!["Jobs (1)", "Stages (2)", "Tasks (3)", "Executors (4)", "SQL (5)", "Environment (6)", "Summary (7)"];
         let tabs = Tabs::new(tab_titles)
             .block(Block::default().borders(Borders::ALL))
-            .style(Style::default().fg(Color::White))
+            .style(Style::default().fg(theme.text_fg))
             .highlight_style(
                 Style::default()
                     .add_modifier(Modifier::BOLD)
-                    .bg(Color::Blue)
-                    .fg(Color::White),
+                    .bg(theme.tab_active_bg)
+                    .fg(theme.tab_active_fg),
             )
             .select(state.selected_tab as usize);
 
         f.render_widget(tabs, area);
     }
 
-    fn draw_footer(&self, f: &mut Frame, area: ratatui::layout::Rect) {
-        let help_text = "Navigation: Tab/Shift+Tab (Switch tabs) | 1-6 (Direct tab) | ↑↓/jk (Navigate) | q/Esc (Quit)";
+    fn draw_footer(&self, f: &mut Frame, area: ratatui::layout::Rect, state: &AppState) {
+        let theme = &state.theme;
+        if let Some((message, _)) = &state.status_message {
+            let paragraph = Paragraph::new(message.as_str())
+                .style(Style::default().fg(theme.status_success))
+                .block(Block::default().borders(Borders::ALL).title("Help"));
+            f.render_widget(paragraph, area);
+            return;
+        }
+
+        let help_text = "Navigation: Tab/Shift+Tab (Switch tabs) | 1-7 (Direct tab) | ↑↓/jk (Navigate) | Enter (Details) | t (Executor timeline) | A (Switch attempt) | f (Failed only) | z (Zebra stripes) | w (Parse warnings) | q/Esc (Quit)";
         let paragraph = Paragraph::new(help_text)
-            .style(Style::default().fg(Color::Gray))
+            .style(Style::default().fg(theme.muted_fg))
             .block(Block::default().borders(Borders::ALL).title("Help"));
 
         f.render_widget(paragraph, area);
@@ -1,4 +1,8 @@
 use ratatui::widgets::TableState;
+use chrono_tz::Tz;
+use std::time::Instant;
+
+use crate::config::Theme;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TabIndex {
@@ -8,6 +12,7 @@ pub enum TabIndex {
     Executors = 3,
     Sql = 4,
     Environment = 5,
+    Summary = 6,
 }
 
 impl TabIndex {
@@ -18,29 +23,51 @@ impl TabIndex {
             TabIndex::Tasks => TabIndex::Executors,
             TabIndex::Executors => TabIndex::Sql,
             TabIndex::Sql => TabIndex::Environment,
-            TabIndex::Environment => TabIndex::Jobs,
+            TabIndex::Environment => TabIndex::Summary,
+            TabIndex::Summary => TabIndex::Jobs,
         }
     }
 
     pub fn previous(&self) -> Self {
         match self {
-            TabIndex::Jobs => TabIndex::Environment,
+            TabIndex::Jobs => TabIndex::Summary,
             TabIndex::Stages => TabIndex::Jobs,
             TabIndex::Tasks => TabIndex::Stages,
             TabIndex::Executors => TabIndex::Tasks,
             TabIndex::Sql => TabIndex::Executors,
             TabIndex::Environment => TabIndex::Sql,
+            TabIndex::Summary => TabIndex::Environment,
         }
     }
 }
 
 pub struct AppState {
     pub selected_tab: TabIndex,
+    pub selected_attempt: usize,
     pub jobs_table_state: TableState,
     pub stages_table_state: TableState,
     pub tasks_table_state: TableState,
     pub executors_table_state: TableState,
     pub sql_table_state: TableState,
+    pub executor_timeline: bool,
+    pub zebra_stripe: bool,
+    pub show_misc_processes: bool,
+    pub show_failed_only: bool,
+    pub detail_open: bool,
+    /// Vertical scroll of the SQL and stage detail popups, which hold plans
+    /// and call sites.
+    pub detail_scroll: u16,
+    /// Whether the SQL detail popup shows the plan before AQE rewrote it.
+    pub show_initial_plan: bool,
+    pub warnings_open: bool,
+    pub warnings_table_state: TableState,
+    pub theme: Theme,
+    /// Timezone every timestamp in the TUI is shown in.
+    pub display_timezone: Tz,
+    /// Short-lived message shown in the footer, e.g. after copying a value.
+    pub status_message: Option<(String, Instant)>,
+    /// Error shown in a popup until the next key press.
+    pub error_message: Option<String>,
 }
 
 impl AppState {
@@ -60,13 +87,30 @@ impl AppState {
         let mut sql_table_state = TableState::default();
         sql_table_state.select(Some(0));
 
+        let mut warnings_table_state = TableState::default();
+        warnings_table_state.select(Some(0));
+
         Self {
             selected_tab: TabIndex::Jobs,
+            selected_attempt: 0,
             jobs_table_state,
             stages_table_state,
             tasks_table_state,
             executors_table_state,
             sql_table_state,
+            executor_timeline: false,
+            zebra_stripe: true,
+            show_misc_processes: false,
+            show_failed_only: false,
+            detail_open: false,
+            detail_scroll: 0,
+            show_initial_plan: false,
+            warnings_open: false,
+            warnings_table_state,
+            theme: Theme::default(),
+            display_timezone: Tz::UTC,
+            status_message: None,
+            error_message: None,
         }
     }
 
@@ -77,4 +121,20 @@ impl AppState {
     pub fn previous_tab(&mut self) {
         self.selected_tab = self.selected_tab.previous();
     }
-}
\ No newline at end of file
+
+    pub fn set_status_message(&mut self, message: impl Into<String>) {
+        self.status_message = Some((message.into(), Instant::now()));
+    }
+
+    pub fn next_attempt(&mut self, attempt_count: usize) {
+        if attempt_count > 0 {
+            self.selected_attempt = (self.selected_attempt + 1) % attempt_count;
+        }
+    }
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,27 @@
+use std::cmp::Reverse;
+
+/// Sort `items` longest first. Items without a duration (still running)
+/// go last. The sort is stable, so items with equal durations keep their
+/// incoming order; sort by ID first for a deterministic tie-break.
+///
+/// Used by the `--sort-by duration` report printers. The TUI tables sort
+/// by ID and have no duration sort to share.
+pub fn sort_by_duration_desc<T, F>(items: &mut [T], get_duration: F)
+where
+    F: Fn(&T) -> Option<i64>,
+{
+    // None orders below every Some, so reversing puts it last
+    items.sort_by_key(|item| Reverse(get_duration(item)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sorts_longest_first_with_running_items_last() {
+        let mut items = vec![(0, Some(50)), (1, None), (2, Some(900)), (3, Some(50))];
+        sort_by_duration_desc(&mut items, |(_, duration)| *duration);
+        assert_eq!(items.iter().map(|(id, _)| *id).collect::<Vec<_>>(), vec![2, 0, 3, 1]);
+    }
+}
@@ -0,0 +1,62 @@
+use std::path::Path;
+
+use spark_tui::models::{JobStatus, TaskStatus};
+use spark_tui::parser;
+
+#[test]
+fn parses_sample_fixture() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sample.jsonl");
+    let (event_log, metadata) = parser::parse_event_log(&fixture).unwrap();
+
+    assert!(event_log.parse_warnings.is_empty(), "unexpected warnings: {:?}", event_log.parse_warnings);
+    assert_eq!(metadata.line_count, 27);
+
+    assert_eq!(event_log.application_info.app_name, "Integration Fixture");
+    assert_eq!(event_log.application_info.app_id, "app-20241201120000-0042");
+    assert!(event_log.application_info.end_time.is_some());
+
+    assert_eq!(event_log.jobs.len(), 2);
+    assert_eq!(event_log.stages.len(), 3);
+    assert_eq!(event_log.tasks.len(), 6);
+    assert_eq!(event_log.executors.len(), 2);
+
+    assert!(event_log.jobs.values().all(|job| matches!(job.status, JobStatus::Succeeded)));
+    assert_eq!(event_log.jobs[&0].stage_ids, vec![0, 1]);
+
+    let task = &event_log.tasks[&0];
+    assert!(matches!(task.status, TaskStatus::Success));
+    let duration = task.finish_time.unwrap() - task.launch_time;
+    assert_eq!(duration.num_milliseconds(), 500);
+
+    assert_eq!(event_log.executors["2"].host, "worker2");
+
+    let stage_tasks: Vec<_> = event_log.tasks_for_stage(1).iter().map(|t| t.task_id).collect();
+    assert_eq!(stage_tasks, vec![2, 3]);
+    assert_eq!(event_log.precompute_aggregates().executor_tasks["1"], vec![0, 2, 4]);
+
+    let summary = event_log.summary();
+    assert_eq!(summary.duration_ms, Some(10_000));
+    assert_eq!(summary.failed_tasks, 0);
+    assert_eq!(summary.total_executor_time_ms, 6 * 400);
+    assert_eq!(summary.total_gc_time_ms, 6 * 10);
+
+    let aggregates = event_log.precompute_aggregates();
+    assert_eq!(aggregates.stage_task_counts[&(2, 0)].total_tasks, 2);
+    assert_eq!(aggregates.executor_task_counts["1"].total_tasks, 3);
+    assert_eq!(aggregates.app_totals.total_tasks, 6);
+    assert_eq!(aggregates.task_status_counts.succeeded, 6);
+    assert_eq!(aggregates.job_status_counts.succeeded, event_log.jobs.len());
+    assert_eq!(aggregates.task_order, vec![0, 1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn validation_reports_dangling_stage_references() {
+    let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sample.jsonl");
+    let (mut event_log, _) = parser::parse_event_log(&fixture).unwrap();
+    assert!(parser::validation::validate_event_log(&event_log).is_empty());
+
+    event_log.stages.remove(&(2, 0));
+    let issues = parser::validation::validate_event_log(&event_log);
+    assert_eq!(issues[0], "Job 1 stage_id 2 not found in stages");
+    assert_eq!(issues.len(), 3, "expected the job and both of its tasks: {:?}", issues);
+}